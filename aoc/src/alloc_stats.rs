@@ -0,0 +1,144 @@
+//! Heap accounting for `aoc bench`. Built with `--features alloc-stats`, the runner installs
+//! `Counting` as its global allocator; without the feature nothing is counted and
+//! [`measure`] returns no usage.
+//!
+//! The counters are per thread, so a measurement only sees the allocations of the code it
+//! runs, not those of other threads.
+
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::{cell::Cell, fmt};
+
+use serde::{Deserialize, Serialize};
+
+/// Heap usage of one step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    /// Most memory the step had allocated at once, on top of what was allocated before it.
+    pub peak_bytes: usize,
+    /// Number of allocations, counting a reallocation as one.
+    pub allocations: usize,
+    /// Total bytes requested, including memory freed again during the step.
+    pub allocated_bytes: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    current: usize,
+    peak: usize,
+    allocations: usize,
+    allocated: usize,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            current: 0,
+            peak: 0,
+            allocations: 0,
+            allocated: 0,
+        })
+    };
+}
+
+/// Passes every request on to the system allocator and counts it.
+#[cfg(feature = "alloc-stats")]
+pub struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+fn update(f: impl FnOnce(&mut Counters)) {
+    // Fails while the thread is being torn down, those allocations are not counted.
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        f(&mut c);
+        c.peak = c.peak.max(c.current);
+        counters.set(c);
+    });
+}
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            update(|c| {
+                c.current += layout.size();
+                c.allocations += 1;
+                c.allocated += layout.size();
+            });
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        update(|c| c.current = c.current.saturating_sub(layout.size()));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            update(|c| {
+                c.current = c.current.saturating_sub(layout.size()) + new_size;
+                c.allocations += 1;
+                c.allocated += new_size.saturating_sub(layout.size());
+            });
+        }
+        new
+    }
+}
+
+/// Runs `f` and returns what it allocated on this thread, `None` without the `alloc-stats`
+/// feature.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+
+    let before = COUNTERS.with(|counters| {
+        let mut c = counters.get();
+        c.peak = c.current;
+        counters.set(c);
+        c
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+    let usage = Usage {
+        peak_bytes: after.peak - before.current,
+        allocations: after.allocations - before.allocations,
+        allocated_bytes: after.allocated - before.allocated,
+    };
+    (result, Some(usage))
+}
+
+/// Bytes in the largest binary unit that keeps the number at least 1.
+struct Bytes(usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut value = self.0 as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        if unit == 0 {
+            write!(f, "{} B", self.0)
+        } else {
+            write!(f, "{value:.1} {}", UNITS[unit])
+        }
+    }
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {} / {}",
+            Bytes(self.peak_bytes),
+            self.allocations,
+            Bytes(self.allocated_bytes)
+        )
+    }
+}
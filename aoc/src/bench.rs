@@ -0,0 +1,235 @@
+//! Times parse, part 1 and part 2 of each day separately over repeated runs, and measures
+//! their heap usage once when built with `--features alloc-stats`.
+
+use std::{
+    fmt, fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{Answer, DynSolution, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::alloc_stats::{self, Usage};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub runs: usize,
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: Stats,
+    /// `None` if the part is unsolved.
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    /// `None` unless built with the `alloc-stats` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Memory>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Memory {
+    pub parse: Usage,
+    /// `None` if the part is unsolved.
+    pub part1: Option<Usage>,
+    pub part2: Option<Usage>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+/// A step whose median got slower than the baseline allows.
+pub struct Regression {
+    pub day: u8,
+    pub step: &'static str,
+    pub baseline: Stats,
+    pub current: Stats,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        Stats {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[samples.len() / 2]),
+            max_ns: nanos(samples[samples.len() - 1]),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = Duration::from_nanos;
+        write!(
+            f,
+            "{:.2?} / {:.2?} / {:.2?}",
+            d(self.min_ns),
+            d(self.median_ns),
+            d(self.max_ns)
+        )
+    }
+}
+
+/// Runs parse and both parts `runs` times on `input`.
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    runs: usize,
+) -> Result<DayReport, common::ParseError> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut parts = [Vec::with_capacity(runs), Vec::with_capacity(runs)];
+    let mut solved = [true, true];
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = solution.parse(input)?;
+        parse.push(start.elapsed());
+
+        for (i, part) in Part::ALL.into_iter().enumerate() {
+            let start = Instant::now();
+            let answer = solution.solve(parsed.as_ref(), part);
+            parts[i].push(start.elapsed());
+            solved[i] = answer != Answer::Unsolved;
+        }
+    }
+
+    let [part1, part2] = parts;
+    Ok(DayReport {
+        day: solution.day(),
+        parse: Stats::from_samples(parse),
+        part1: solved[0].then(|| Stats::from_samples(part1)),
+        part2: solved[1].then(|| Stats::from_samples(part2)),
+        memory: measure_memory(solution, input)?,
+    })
+}
+
+/// Heap usage of one more run of each step, `None` if allocations are not counted.
+fn measure_memory(
+    solution: &dyn DynSolution,
+    input: &str,
+) -> Result<Option<Memory>, common::ParseError> {
+    let (parsed, parse) = alloc_stats::measure(|| solution.parse(input));
+    let (parsed, Some(parse)) = (parsed?, parse) else {
+        return Ok(None);
+    };
+    let [part1, part2] = Part::ALL.map(|part| {
+        let (answer, usage) = alloc_stats::measure(|| solution.solve(parsed.as_ref(), part));
+        usage.filter(|_| answer != Answer::Unsolved)
+    });
+    Ok(Some(Memory {
+        parse,
+        part1,
+        part2,
+    }))
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report, String> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        serde_json::from_str(&text).map_err(|e| format!("cannot parse {}: {e}", path.display()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    pub fn print_table(&self) {
+        println!(
+            "{:>3}  {:<32} {:<32} {:<32}",
+            "day", "parse min/median/max", "part 1", "part 2"
+        );
+        let show = |stats: Option<Stats>| match stats {
+            Some(stats) => stats.to_string(),
+            None => "unsolved".to_string(),
+        };
+        for day in &self.days {
+            println!(
+                "{:>3}  {:<32} {:<32} {:<32}",
+                day.day,
+                day.parse.to_string(),
+                show(day.part1),
+                show(day.part2)
+            );
+        }
+
+        if self.days.iter().all(|day| day.memory.is_none()) {
+            return;
+        }
+        println!();
+        println!(
+            "{:>3}  {:<32} {:<32} {:<32}",
+            "day", "parse peak/allocations/bytes", "part 1", "part 2"
+        );
+        let show = |usage: Option<Usage>| match usage {
+            Some(usage) => usage.to_string(),
+            None => "unsolved".to_string(),
+        };
+        for day in &self.days {
+            let Some(memory) = day.memory else {
+                continue;
+            };
+            println!(
+                "{:>3}  {:<32} {:<32} {:<32}",
+                day.day,
+                memory.parse.to_string(),
+                show(memory.part1),
+                show(memory.part2)
+            );
+        }
+    }
+
+    /// Steps whose median is more than `threshold` percent slower than in `baseline`.
+    /// Days or parts missing from the baseline are not compared.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for day in &self.days {
+            let Some(base) = baseline.days.iter().find(|b| b.day == day.day) else {
+                continue;
+            };
+            let steps = [
+                ("parse", Some(day.parse), Some(base.parse)),
+                ("part 1", day.part1, base.part1),
+                ("part 2", day.part2, base.part2),
+            ];
+            for (step, current, base) in steps {
+                let (Some(current), Some(base)) = (current, base) else {
+                    continue;
+                };
+                let allowed = base.median_ns as f64 * (1.0 + threshold / 100.0);
+                if current.median_ns as f64 > allowed {
+                    regressions.push(Regression {
+                        day: day.day,
+                        step,
+                        baseline: base,
+                        current,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+}
+
+impl fmt::Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let change =
+            (self.current.median_ns as f64 / self.baseline.median_ns.max(1) as f64 - 1.0) * 100.0;
+        write!(
+            f,
+            "day {} {}: median {:.2?}, baseline {:.2?} (+{change:.0}%)",
+            self.day,
+            self.step,
+            Duration::from_nanos(self.current.median_ns),
+            Duration::from_nanos(self.baseline.median_ns),
+        )
+    }
+}
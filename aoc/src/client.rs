@@ -0,0 +1,188 @@
+//! Talks to the puzzle server: downloads inputs and submits answers.
+//!
+//! The server is `https://adventofcode.com` unless `$AOC_SERVER` or `--server` points
+//! somewhere else, like the stand-in server the tests run. Requests are authenticated with the
+//! `session` cookie of a logged in browser, taken from `$AOC_SESSION`.
+
+use std::{env, fmt, fs, io::Read, path::Path, time::Duration};
+
+use common::Part;
+
+use crate::ledger::Verdict;
+
+pub const YEAR: u16 = 2024;
+pub const DEFAULT_SERVER: &str = "https://adventofcode.com";
+pub const SERVER_ENV: &str = "AOC_SERVER";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = "aoc2024 runner (github.com/jtyrmn/aoc2024)";
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Accepted,
+    Rejected(Verdict),
+    /// Submitted too soon after the previous answer, nothing was checked.
+    Wait(Duration),
+    /// The part was solved already, nothing was checked.
+    AlreadySolved,
+    /// None of the above, with the text of the page's main element.
+    Unrecognized(String),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// A client for `server`, falling back to `$AOC_SERVER` and then the real server, with the
+    /// session from `$AOC_SESSION`.
+    pub fn from_env(server: Option<&str>) -> Result<Client, String> {
+        let session = env::var(SESSION_ENV)
+            .map_err(|_| format!("set {SESSION_ENV} to the session cookie of the website"))?;
+        let server = match server {
+            Some(server) => server.to_string(),
+            None => env::var(SERVER_ENV).unwrap_or_else(|_| DEFAULT_SERVER.to_string()),
+        };
+        Ok(Client::new(&server, session.trim()))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}/{path}", self.base_url)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, String> {
+        let url = self.url(day, "input");
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|e| format!("cannot fetch {url}: {e}"))?;
+        let mut text = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("cannot read {url}: {e}"))?;
+        Ok(text)
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Response, String> {
+        let url = self.url(day, "answer");
+        let level = part.number().to_string();
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| format!("cannot submit to {url}: {e}"))?
+            .into_string()
+            .map_err(|e| format!("cannot read the response from {url}: {e}"))?;
+        Ok(parse_response(&page))
+    }
+}
+
+/// Downloads the input of `day` to `path` unless it is there already. Returns whether it was
+/// downloaded.
+pub fn fetch_cached(client: &Client, day: u8, path: &Path, force: bool) -> Result<bool, String> {
+    if path.exists() && !force {
+        return Ok(false);
+    }
+    let input = client.fetch_input(day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    fs::write(path, input).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    Ok(true)
+}
+
+/// Reads the verdict out of the page the server answers a submission with.
+pub fn parse_response(page: &str) -> Response {
+    let text = main_text(page);
+    if text.contains("That's the right answer") {
+        return Response::Accepted;
+    }
+    if text.contains("That's not the right answer") {
+        let verdict = if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        return Response::Rejected(verdict);
+    }
+    if text.contains("You gave an answer too recently") {
+        return Response::Wait(wait_time(&text).unwrap_or(Duration::from_secs(60)));
+    }
+    if text.contains("You don't seem to be solving the right level") {
+        return Response::AlreadySolved;
+    }
+    Response::Unrecognized(text)
+}
+
+/// The text of the page's `<main>` element, or of the whole page, without tags.
+fn main_text(page: &str) -> String {
+    let main = match (page.find("<main>"), page.find("</main>")) {
+        (Some(start), Some(end)) if start < end => &page[start + "<main>".len()..end],
+        _ => page,
+    };
+    let mut text = String::with_capacity(main.len());
+    let mut in_tag = false;
+    for c in main.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "You have 1m 23s left to wait".
+fn wait_time(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for amount in text[start..end].split_whitespace() {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "s" => number,
+            "m" => number * 60,
+            "h" => number * 3600,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Accepted => write!(f, "accepted"),
+            Response::Rejected(verdict) => write!(f, "rejected, {verdict}"),
+            Response::Wait(wait) => write!(f, "submitted too recently, wait {}s", wait.as_secs()),
+            Response::AlreadySolved => write!(f, "already solved"),
+            Response::Unrecognized(text) => write!(f, "unrecognized response: {text}"),
+        }
+    }
+}
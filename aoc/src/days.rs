@@ -0,0 +1,42 @@
+use std::path::{Path, PathBuf};
+
+use common::{erase, DynSolution, Input, InputOptions};
+
+pub fn solutions() -> Vec<Box<dyn DynSolution>> {
+    vec![
+        erase::<day1::Day1>(),
+        erase::<day2::Day2>(),
+        erase::<day3::Day3>(),
+        erase::<day4::Day4>(),
+        erase::<day5::Day5>(),
+        erase::<day6::Day6>(),
+        erase::<day7::Day7>(),
+        erase::<day8::Day8>(),
+        erase::<day9::Day9>(),
+        erase::<day10::Day10>(),
+        erase::<day11::Day11>(),
+        erase::<day12::Day12>(),
+        erase::<day13::Day13>(),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Box<dyn DynSolution>> {
+    solutions().into_iter().find(|s| s.day() == day)
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// The crate directory of a day, `dayN/` in the workspace.
+pub fn day_dir(day: u8) -> PathBuf {
+    workspace_root().join(format!("day{day}"))
+}
+
+pub fn input(day: u8, options: &InputOptions) -> Input {
+    options.resolve(day, &day_dir(day))
+}
+
+pub fn ledger(day: u8) -> PathBuf {
+    day_dir(day).join("ledger.toml")
+}
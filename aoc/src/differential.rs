@@ -0,0 +1,220 @@
+//! Differential testing: runs a day's solution and its [reference](common::Solution::reference)
+//! on random inputs and shrinks the first input they disagree on.
+
+use std::{
+    collections::HashSet,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use common::{
+    budget::{self, Budget},
+    Answer, DynSolution, Part,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::panics;
+
+/// An input on which the solution and its reference disagree.
+#[derive(Debug)]
+pub struct Divergence {
+    pub day: u8,
+    pub part: Part,
+    /// Which of the random inputs diverged first, counting from 0.
+    pub case: usize,
+    /// The diverging input after shrinking it.
+    pub input: String,
+    pub answer: String,
+    pub reference: String,
+}
+
+/// Checks `part` of `solution` against its reference on `cases` random inputs of `size`.
+/// Returns how many inputs were compared; inputs that do not parse are skipped, and so are
+/// those on which either implementation runs out of `budget`, which keeps shrinking from
+/// getting stuck on an input that makes one of them loop.
+///
+/// Panics in either implementation count as a divergence. The panic hook is silenced while
+/// checking so that shrinking a panicking input does not flood stderr.
+pub fn check(
+    solution: &dyn DynSolution,
+    part: Part,
+    seed: u64,
+    cases: usize,
+    size: usize,
+    budget: Budget,
+) -> Result<usize, Box<Divergence>> {
+    panics::silenced(|| check_cases(solution, part, seed, cases, size, budget))
+}
+
+fn check_cases(
+    solution: &dyn DynSolution,
+    part: Part,
+    seed: u64,
+    cases: usize,
+    size: usize,
+    budget: Budget,
+) -> Result<usize, Box<Divergence>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut compared = 0;
+    for case in 0..cases {
+        let Some(input) = solution.generate(&mut rng, size) else {
+            break;
+        };
+        let Some(outcome) = compare(solution, part, &input, budget) else {
+            continue;
+        };
+        compared += 1;
+        if outcome.0 == outcome.1 {
+            continue;
+        }
+
+        let input = minimize(input, |candidate| {
+            compare(solution, part, candidate, budget).is_some_and(|(a, r)| a != r)
+        });
+        let (answer, reference) = compare(solution, part, &input, budget).unwrap();
+        return Err(Box::new(Divergence {
+            day: solution.day(),
+            part,
+            case,
+            input,
+            answer,
+            reference,
+        }));
+    }
+    Ok(compared)
+}
+
+/// The answers of the solution and of the reference, `None` if the input does not parse or
+/// either runs out of `budget`.
+fn compare(
+    solution: &dyn DynSolution,
+    part: Part,
+    input: &str,
+    budget: Budget,
+) -> Option<(String, String)> {
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| solution.parse(input)))
+        .ok()?
+        .ok()?;
+    let run = |f: &dyn Fn() -> Option<Answer>| match panic::catch_unwind(AssertUnwindSafe(|| {
+        budget::limit(budget, f)
+    })) {
+        Ok(Ok(Some(answer))) => Some(answer.to_string()),
+        Ok(Ok(None)) => Some("no reference".to_string()),
+        Ok(Err(_)) => None,
+        Err(payload) => Some(format!("panicked: {}", panics::message(payload.as_ref()))),
+    };
+    let answer = run(&|| Some(solution.solve(parsed.as_ref(), part)))?;
+    let reference = run(&|| solution.solve_reference(parsed.as_ref(), part))?;
+    Some((answer, reference))
+}
+
+/// Greedily applies the first simplification of [`shrink`] that still `diverges`, until
+/// none does. Inputs tried before are skipped, since some simplifications undo others.
+pub fn minimize(mut input: String, diverges: impl Fn(&str) -> bool) -> String {
+    let mut tried = HashSet::from([input.clone()]);
+    'simplify: loop {
+        for candidate in shrink(&input) {
+            if tried.insert(candidate.clone()) && diverges(&candidate) {
+                input = candidate;
+                continue 'simplify;
+            }
+        }
+        return input;
+    }
+}
+
+/// Simpler variants of `input`, roughly from the biggest simplification to the smallest:
+/// without one of its lines, without one of its words, with a smaller number, and with a
+/// character replaced by the most common one (to clear single cells of a map).
+fn shrink(input: &str) -> Vec<String> {
+    let mut candidates = Vec::new();
+
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() > 1 {
+        for i in 0..lines.len() {
+            let mut fewer = lines.clone();
+            fewer.remove(i);
+            candidates.push(fewer.join("\n") + "\n");
+        }
+    }
+
+    for (start, word) in spans(input, |c| !c.is_whitespace()) {
+        // Take the spaces after the word with it, or before it if it ends the line.
+        let (mut start, mut end) = (start, start + word.len());
+        let after = input[end..].len() - input[end..].trim_start_matches(' ').len();
+        if after > 0 {
+            end += after;
+        } else {
+            start = input[..start].trim_end_matches(' ').len();
+        }
+        if start > 0 || end < input.len() {
+            candidates.push(format!("{}{}", &input[..start], &input[end..]));
+        }
+    }
+
+    for (start, digits) in spans(input, |c| c.is_ascii_digit()) {
+        let end = start + digits.len();
+        let smaller = ["0", "1", &digits[..digits.len() / 2], &digits[1..]];
+        for smaller in smaller
+            .into_iter()
+            .filter(|s| !s.is_empty() && *s != digits)
+        {
+            candidates.push(format!("{}{smaller}{}", &input[..start], &input[end..]));
+        }
+    }
+
+    if let Some(common) = most_common_char(input) {
+        for (i, c) in input.char_indices() {
+            if c != common && !c.is_whitespace() {
+                let end = i + c.len_utf8();
+                candidates.push(format!("{}{common}{}", &input[..i], &input[end..]));
+            }
+        }
+    }
+
+    candidates.retain(|c| c != input);
+    candidates
+}
+
+/// The maximal runs of characters matching `predicate`, with their byte offsets.
+fn spans(input: &str, predicate: impl Fn(char) -> bool) -> Vec<(usize, &str)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (i, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        match (start, predicate(c) && i < input.len()) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                spans.push((s, &input[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+fn most_common_char(input: &str) -> Option<char> {
+    let mut counts = std::collections::HashMap::new();
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(c, count)| (count, std::cmp::Reverse(c)))
+        .map(|(c, _)| c)
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "day {} part {}: random input #{} diverges, shrunk to:",
+            self.day, self.part, self.case
+        )?;
+        for line in self.input.lines() {
+            writeln!(f, "    {line}")?;
+        }
+        writeln!(f, "solution:  {}", self.answer)?;
+        write!(f, "reference: {}", self.reference)
+    }
+}
@@ -0,0 +1,115 @@
+//! The example inputs bundled with each day, `dayN/input_test*.txt`, and their expected answers.
+//!
+//! Every example sits next to a `.expected` file with the same stem, listing the answers the
+//! puzzle text gives for it:
+//!
+//! ```text
+//! # from the puzzle description
+//! part 1: 14
+//! part 2: 34 (known failing)
+//! ```
+//!
+//! A part can be left out when there is no known answer for it. `(known failing)` marks an
+//! answer the solution does not produce yet; it is reported instead of failing the test suite.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::Part;
+
+use crate::days;
+
+#[derive(Debug)]
+pub struct Example {
+    pub input: PathBuf,
+    /// `None` if the example has no `.expected` file next to it.
+    pub expected: Option<Expected>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Expected {
+    part1: Option<ExpectedAnswer>,
+    part2: Option<ExpectedAnswer>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub answer: String,
+    pub known_failing: bool,
+}
+
+/// The examples of `day`, sorted by file name.
+pub fn discover(day: u8) -> io::Result<Vec<Example>> {
+    let mut inputs = Vec::new();
+    for entry in fs::read_dir(days::day_dir(day))? {
+        let path = entry?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if name.starts_with("input_test") && name.ends_with(".txt") {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+
+    inputs
+        .into_iter()
+        .map(|input| {
+            let expected = match fs::read_to_string(expected_path(&input)) {
+                Ok(text) => Some(Expected::parse(&text).map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: {e}", expected_path(&input).display()),
+                    )
+                })?),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+                Err(e) => return Err(e),
+            };
+            Ok(Example { input, expected })
+        })
+        .collect()
+}
+
+/// `input_test.txt` is checked against `input_test.expected`.
+pub fn expected_path(input: &Path) -> PathBuf {
+    input.with_extension("expected")
+}
+
+impl Expected {
+    pub fn parse(text: &str) -> Result<Expected, String> {
+        let mut expected = Expected::default();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (part, answer) = line
+                .split_once(':')
+                .ok_or_else(|| format!("expected `part N: answer`, got `{line}`"))?;
+            let slot = match part.trim() {
+                "part 1" => &mut expected.part1,
+                "part 2" => &mut expected.part2,
+                other => return Err(format!("unknown part `{other}`")),
+            };
+            let answer = answer.trim();
+            let (answer, known_failing) = match answer.strip_suffix("(known failing)") {
+                Some(answer) => (answer.trim_end(), true),
+                None => (answer, false),
+            };
+            if answer.is_empty() {
+                return Err(format!("missing answer for {}", part.trim()));
+            }
+            *slot = Some(ExpectedAnswer {
+                answer: answer.to_string(),
+                known_failing,
+            });
+        }
+        Ok(expected)
+    }
+
+    pub fn part(&self, part: Part) -> Option<&ExpectedAnswer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
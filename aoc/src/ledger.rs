@@ -0,0 +1,283 @@
+//! Submission history for each day, kept in `dayN/ledger.toml`:
+//!
+//! ```toml
+//! [part2]
+//! accepted = 80570939
+//! rejected = [
+//!     { answer = 48810620, verdict = "too-low" },
+//!     { answer = 94785627, verdict = "too-high" },
+//! ]
+//! ```
+//!
+//! `known-failing = true` in a part marks a solution that is known not to give the accepted
+//! answer any more, or to give one that was already rejected. `verify` reports it instead of
+//! failing.
+
+use std::{fmt, fs, io, path::Path};
+
+use common::{Answer, Part};
+use serde::Deserialize;
+use toml_edit::{Array, DocumentMut, InlineTable, Item, Table, Value};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ledger {
+    #[serde(default)]
+    part1: PartLedger,
+    #[serde(default)]
+    part2: PartLedger,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartLedger {
+    pub accepted: Option<Recorded>,
+    #[serde(default)]
+    pub rejected: Vec<Guess>,
+    #[serde(default)]
+    pub known_failing: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Guess {
+    pub answer: Recorded,
+    pub verdict: Verdict,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    TooHigh,
+    TooLow,
+    /// Rejected without a hint in which direction the answer is.
+    Wrong,
+}
+
+impl Verdict {
+    /// How the verdict is spelled in the ledger.
+    fn key(self) -> &'static str {
+        match self {
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+/// An answer as written in the ledger.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Recorded {
+    Integer(i64),
+    Text(String),
+}
+
+impl Recorded {
+    /// The answer as it would be written to the ledger, `None` if it is unsolved or an
+    /// integer too large for TOML.
+    pub fn from_answer(answer: &Answer) -> Option<Recorded> {
+        match answer {
+            Answer::Integer(v) => i64::try_from(*v).ok().map(Recorded::Integer),
+            Answer::Text(s) => Some(Recorded::Text(s.clone())),
+            Answer::Unsolved => None,
+        }
+    }
+
+    /// Parses an answer typed on the command line, an integer if it looks like one.
+    pub fn parse(answer: &str) -> Recorded {
+        match answer.parse() {
+            Ok(v) => Recorded::Integer(v),
+            Err(_) => Recorded::Text(answer.to_string()),
+        }
+    }
+
+    pub fn to_answer(&self) -> Answer {
+        match self {
+            Recorded::Integer(v) => Answer::from(*v),
+            Recorded::Text(s) => Answer::from(s.as_str()),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Recorded::Integer(v) => Value::from(*v),
+            Recorded::Text(s) => Value::from(s.as_str()),
+        }
+    }
+
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match (self, answer) {
+            (Recorded::Integer(r), Answer::Integer(a)) => *r as i128 == *a,
+            (Recorded::Text(r), Answer::Text(a)) => r == a,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Recorded::Integer(v) => write!(f, "{v}"),
+            Recorded::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// How a freshly computed answer compares to the ledger.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    /// Same as the accepted answer.
+    Accepted,
+    /// There is an accepted answer and this is not it.
+    Changed { accepted: Recorded },
+    /// No accepted answer yet, but the answer was already rejected or falls outside the
+    /// bounds given by earlier too high/too low guesses.
+    Rejected { reason: String },
+    /// No accepted answer yet and nothing rules this one out.
+    Candidate,
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`, an empty ledger if there is no such file.
+    pub fn load(path: &Path) -> Result<Ledger, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Ledger::default()),
+            Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+        };
+        toml::from_str(&text).map_err(|e| format!("cannot parse {}: {e}", path.display()))
+    }
+
+    pub fn part(&self, part: Part) -> &PartLedger {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+}
+
+impl PartLedger {
+    /// Largest answer known to be too low.
+    pub fn lower_bound(&self) -> Option<i64> {
+        self.bound(Verdict::TooLow).max()
+    }
+
+    /// Smallest answer known to be too high.
+    pub fn upper_bound(&self) -> Option<i64> {
+        self.bound(Verdict::TooHigh).min()
+    }
+
+    fn bound(&self, verdict: Verdict) -> impl Iterator<Item = i64> + '_ {
+        self.rejected
+            .iter()
+            .filter(move |g| g.verdict == verdict)
+            .filter_map(|g| match g.answer {
+                Recorded::Integer(v) => Some(v),
+                Recorded::Text(_) => None,
+            })
+    }
+
+    pub fn check(&self, answer: &Answer) -> Check {
+        if let Some(accepted) = &self.accepted {
+            return if accepted.matches(answer) {
+                Check::Accepted
+            } else {
+                Check::Changed {
+                    accepted: accepted.clone(),
+                }
+            };
+        }
+
+        if let Some(guess) = self.rejected.iter().find(|g| g.answer.matches(answer)) {
+            let note = guess
+                .note
+                .as_ref()
+                .map(|note| format!(", {note}"))
+                .unwrap_or_default();
+            return Check::Rejected {
+                reason: format!(
+                    "{} was already rejected ({}{note})",
+                    guess.answer, guess.verdict
+                ),
+            };
+        }
+        if let Answer::Integer(v) = answer {
+            if let Some(low) = self.lower_bound().filter(|low| *v <= *low as i128) {
+                return Check::Rejected {
+                    reason: format!("{v} is not above {low}, which is too low"),
+                };
+            }
+            if let Some(high) = self.upper_bound().filter(|high| *v >= *high as i128) {
+                return Check::Rejected {
+                    reason: format!("{v} is not below {high}, which is too high"),
+                };
+            }
+        }
+        Check::Candidate
+    }
+}
+
+/// Adds the outcome of a submission to the ledger at `path`, creating it if needed: an
+/// accepted answer with no verdict, a rejected one otherwise. Comments and formatting of the
+/// existing entries are kept.
+pub fn record(
+    path: &Path,
+    part: Part,
+    answer: &Recorded,
+    verdict: Option<Verdict>,
+) -> Result<(), String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+    };
+    let mut document = text
+        .parse::<DocumentMut>()
+        .map_err(|e| format!("cannot parse {}: {e}", path.display()))?;
+
+    let key = format!("part{}", part.number());
+    let table = document
+        .entry(&key)
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| format!("{}: {key} is not a table", path.display()))?;
+    match verdict {
+        None => {
+            table.insert("accepted", Item::Value(answer.to_value()));
+        }
+        Some(verdict) => {
+            let rejected = table
+                .entry("rejected")
+                .or_insert_with(|| Item::Value(Value::Array(Array::new())))
+                .as_array_mut()
+                .ok_or_else(|| format!("{}: {key}.rejected is not an array", path.display()))?;
+            let mut guess = InlineTable::new();
+            guess.insert("answer", answer.to_value());
+            guess.insert("verdict", Value::from(verdict.key()));
+            rejected.push(guess);
+            // One guess per line, like the hand-written ledgers.
+            for guess in rejected.iter_mut() {
+                guess.decor_mut().set_prefix("\n    ");
+                guess.decor_mut().set_suffix("");
+            }
+            rejected.set_trailing("\n");
+            rejected.set_trailing_comma(true);
+        }
+    }
+
+    fs::write(path, document.to_string())
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! The pieces of the runner that the integration tests use as well.
+
+pub mod client;
+pub mod days;
+pub mod differential;
+pub mod examples;
+pub mod ledger;
+mod panics;
+pub mod parallel;
+pub mod scaffold;
+pub mod watch;
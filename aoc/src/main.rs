@@ -0,0 +1,701 @@
+mod alloc_stats;
+mod bench;
+
+use std::{
+    any::Any,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::days;
+use aoc::{
+    client::{self, Client, Response},
+    differential,
+    ledger::{self, Check, Ledger, Recorded},
+    parallel::{self, DayRun},
+    scaffold, watch,
+};
+use bench::Report;
+use clap::{Args, Parser, Subcommand};
+use common::{
+    budget::{self, Budget},
+    output::{self, Format, Record},
+    Answer, DynSolution, Input, InputOptions, Part,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc_stats::Counting = alloc_stats::Counting;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    /// Directory with one dayN.txt input per day, instead of dayN/input.txt [env: AOC_INPUTS]
+    #[arg(long, global = true)]
+    inputs: Option<PathBuf>,
+    /// Puzzle server to fetch inputs from and submit answers to [env: AOC_SERVER]
+    #[arg(long, global = true)]
+    server: Option<String>,
+    /// Only log errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Log progress with -v, debug traces from the solutions with -vv
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, or every day with --all
+    Run(RunArgs),
+    /// Re-run solutions on their real input and compare against each day's ledger
+    Verify(VerifyArgs),
+    /// Time parse, part 1 and part 2 of each day over repeated runs
+    Bench(BenchArgs),
+    /// Download a day's input from the puzzle server unless it is already there
+    Fetch(FetchArgs),
+    /// Submit an answer to the puzzle server and record the verdict in the ledger
+    Submit(SubmitArgs),
+    /// Create a dayN crate with stub solutions and add it to the workspace and the runner
+    New(NewArgs),
+    /// Check solutions against their slower reference implementations on random inputs
+    Diff(DiffArgs),
+    /// Print a random puzzle input
+    Gen(GenArgs),
+    /// Re-run a day whenever its sources or its input change
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u8>,
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Puzzle input, `-` for stdin
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+    /// Use the example input, dayN/input_test.txt
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Run every day with its default input, several at once, and print a summary table
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// How many days --all runs at once, defaults to the number of CPUs
+    #[arg(long, requires = "all", value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Also write the --all summary as JSON to this file, `-` for stdout
+    #[arg(long, requires = "all")]
+    json: Option<PathBuf>,
+    /// Give up on a part after this many seconds
+    #[arg(long, value_parser = seconds)]
+    timeout: Option<Duration>,
+    /// Give up on a part after this many steps of its main loop
+    #[arg(long)]
+    max_steps: Option<u64>,
+    /// How to print the answers: plain, json (one object per line) or tsv. With --all, plain
+    /// prints a summary table
+    #[arg(long, default_value_t = Format::Plain)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    #[arg(long)]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Only benchmark this day
+    #[arg(long)]
+    day: Option<u8>,
+    /// How many times each step is run
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Also write the results as JSON to this file, `-` for stdout
+    #[arg(long)]
+    json: Option<PathBuf>,
+    /// Flag steps that got slower than in this JSON report from an earlier --json run
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// How much slower than the baseline a median may be before it is flagged, in percent
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(long)]
+    day: u8,
+    /// Download again even if the input is already there
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(long)]
+    day: u8,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Answer to submit, defaults to running the solution on the real input
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Only check this day, defaults to every day with a reference implementation
+    #[arg(long)]
+    day: Option<u8>,
+    /// How many random inputs to try per part
+    #[arg(long, default_value_t = 100)]
+    cases: usize,
+    /// Seed for the random inputs
+    #[arg(long, default_value_t = 2024)]
+    seed: u64,
+    /// Size of the random inputs, see `Solution::generate`
+    #[arg(long, default_value_t = 8)]
+    size: usize,
+    /// Skip an input once either implementation runs this many seconds on it
+    #[arg(long, default_value = "10", value_parser = seconds)]
+    timeout: Duration,
+    /// Skip an input once either implementation takes this many steps of its main loop on it
+    #[arg(long)]
+    max_steps: Option<u64>,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    #[arg(long)]
+    day: u8,
+    /// The same seed and size always give the same input
+    #[arg(long, default_value_t = 2024)]
+    seed: u64,
+    /// Size of the input, see `Solution::generate`
+    #[arg(long, default_value_t = 8)]
+    size: usize,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    #[arg(long)]
+    day: u8,
+    /// Puzzle input to watch and run on
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Use the example input, dayN/input_test.txt
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+}
+
+/// A duration given in seconds, which may have a fraction.
+fn seconds(arg: &str) -> Result<Duration, String> {
+    let seconds: f64 = arg.parse().map_err(|e| format!("{e}"))?;
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| "expected a finite, non-negative number of seconds".to_string())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    common::init_logging(common::level(cli.quiet, cli.verbose));
+    let inputs = InputOptions {
+        inputs_dir: cli.inputs,
+        ..InputOptions::default()
+    };
+    match cli.command {
+        Command::Run(args) => run(args, inputs),
+        Command::Verify(args) => verify(args, &inputs),
+        Command::Bench(args) => bench(args, &inputs),
+        Command::Fetch(args) => fetch(args, &inputs, cli.server.as_deref()),
+        Command::Submit(args) => submit(args, &inputs, cli.server.as_deref()),
+        Command::New(args) => new(args),
+        Command::Diff(args) => diff(args),
+        Command::Gen(args) => gen(args),
+        Command::Watch(args) => watch(args, inputs),
+    }
+}
+
+fn run(args: RunArgs, inputs: InputOptions) -> ExitCode {
+    let inputs = InputOptions {
+        path: args.input,
+        example: args.example,
+        ..inputs
+    };
+    let parts = match args.part {
+        Some(part) => vec![Part::try_from(part).unwrap()],
+        None => Part::ALL.to_vec(),
+    };
+    let budget = Budget {
+        max_steps: args.max_steps,
+        timeout: args.timeout,
+    };
+
+    if args.all {
+        return run_all(
+            &inputs,
+            &parts,
+            budget,
+            args.format,
+            args.jobs,
+            args.json.as_deref(),
+        );
+    }
+
+    let day = args.day.unwrap();
+    let Some(solution) = days::find(day) else {
+        eprintln!("no solution for day {day}");
+        return ExitCode::FAILURE;
+    };
+    if run_day(
+        solution.as_ref(),
+        &days::input(day, &inputs),
+        &parts,
+        budget,
+        args.format,
+    ) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_all(
+    inputs: &InputOptions,
+    parts: &[Part],
+    budget: Budget,
+    format: Format,
+    jobs: Option<u32>,
+    json: Option<&Path>,
+) -> ExitCode {
+    let days: Vec<_> = days::solutions()
+        .into_iter()
+        .map(|solution| {
+            let input = days::input(solution.day(), inputs);
+            (solution, input)
+        })
+        .collect();
+    let threads = match jobs {
+        Some(jobs) => jobs as usize,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let summary = parallel::run_all(&days, parts, budget, threads);
+
+    match format {
+        Format::Plain => summary.print_table(),
+        format => {
+            let records: Vec<_> = summary.days.iter().flat_map(DayRun::records).collect();
+            format.print(&records);
+            for failure in summary.failures() {
+                eprintln!("{failure}");
+            }
+        }
+    }
+    match json {
+        Some(path) if path == Path::new("-") => println!("{}", summary.to_json()),
+        Some(path) => {
+            if let Err(e) = fs::write(path, summary.to_json()) {
+                eprintln!("cannot write {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        None => {}
+    }
+
+    if summary.succeeded() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_day(
+    solution: &dyn DynSolution,
+    input: &Input,
+    parts: &[Part],
+    budget: Budget,
+    format: Format,
+) -> bool {
+    let Some((text, parsed)) = load(solution, input) else {
+        return false;
+    };
+    let hash = output::input_hash(&text);
+    if let Some(header) = format.header() {
+        println!("{header}");
+    }
+    let mut success = true;
+    for &part in parts {
+        let start = Instant::now();
+        match budget::limit(budget, || solution.solve(parsed.as_ref(), part)) {
+            Ok(answer) => {
+                let record = Record::new(solution.day(), part, answer, start.elapsed(), &hash);
+                println!("{}", format.format(&record));
+            }
+            Err(exceeded) => {
+                eprintln!("part {part}: {exceeded}");
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+/// Reads and parses `input`, reporting any error on stderr.
+fn load(solution: &dyn DynSolution, input: &Input) -> Option<(String, Box<dyn Any>)> {
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("cannot read {input}: {e}");
+            return None;
+        }
+    };
+    match solution.parse(&text) {
+        Ok(parsed) => Some((text, parsed)),
+        Err(e) => {
+            eprint!("{}", e.with_file(input.to_string()).render());
+            None
+        }
+    }
+}
+
+/// The solution for `day`, or all of them if no day is given.
+fn select(day: Option<u8>) -> Option<Vec<Box<dyn DynSolution>>> {
+    match day {
+        Some(day) => match days::find(day) {
+            Some(solution) => Some(vec![solution]),
+            None => {
+                eprintln!("no solution for day {day}");
+                None
+            }
+        },
+        None => Some(days::solutions()),
+    }
+}
+
+fn verify(args: VerifyArgs, inputs: &InputOptions) -> ExitCode {
+    let Some(solutions) = select(args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut success = true;
+    for solution in solutions {
+        let day = solution.day();
+        let ledger = match Ledger::load(&days::ledger(day)) {
+            Ok(ledger) => ledger,
+            Err(e) => {
+                eprintln!("{e}");
+                success = false;
+                continue;
+            }
+        };
+        let Some((_, parsed)) = load(solution.as_ref(), &days::input(day, inputs)) else {
+            success = false;
+            continue;
+        };
+
+        for part in Part::ALL {
+            let answer = solution.solve(parsed.as_ref(), part);
+            if answer == Answer::Unsolved {
+                println!("day {day} part {part}: unsolved");
+                continue;
+            }
+            let ledger = ledger.part(part);
+            let failure = match ledger.check(&answer) {
+                Check::Accepted => {
+                    println!("day {day} part {part}: {answer} (accepted)");
+                    continue;
+                }
+                Check::Candidate => {
+                    println!("day {day} part {part}: {answer} (not yet accepted)");
+                    continue;
+                }
+                Check::Changed { accepted } => format!("got {answer} but {accepted} was accepted"),
+                Check::Rejected { reason } => format!("refusing to report answer: {reason}"),
+            };
+            if ledger.known_failing {
+                println!("day {day} part {part}: known failing, {failure}");
+            } else {
+                println!("day {day} part {part}: FAILED, {failure}");
+                success = false;
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: BenchArgs, inputs: &InputOptions) -> ExitCode {
+    let Some(solutions) = select(args.day) else {
+        return ExitCode::FAILURE;
+    };
+    let baseline = match args.baseline.as_deref().map(Report::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut report = Report {
+        runs: args.runs as usize,
+        days: Vec::new(),
+    };
+    for solution in solutions {
+        let input = days::input(solution.day(), inputs);
+        let text = match input.read() {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("cannot read {input}: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        match bench::bench_day(solution.as_ref(), &text, report.runs) {
+            Ok(day) => report.days.push(day),
+            Err(e) => {
+                eprint!("{}", e.with_file(input.to_string()).render());
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    report.print_table();
+    match args.json.as_deref() {
+        Some(path) if path == Path::new("-") => println!("{}", report.to_json()),
+        Some(path) => {
+            if let Err(e) = fs::write(path, report.to_json()) {
+                eprintln!("cannot write {}: {e}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        None => {}
+    }
+
+    let Some(baseline) = baseline else {
+        return ExitCode::SUCCESS;
+    };
+    let regressions = report.regressions(&baseline, args.threshold);
+    if regressions.is_empty() {
+        println!(
+            "no step is more than {}% slower than the baseline",
+            args.threshold
+        );
+        return ExitCode::SUCCESS;
+    }
+    for regression in &regressions {
+        println!("SLOWER {regression}");
+    }
+    ExitCode::FAILURE
+}
+
+fn fetch(args: FetchArgs, inputs: &InputOptions, server: Option<&str>) -> ExitCode {
+    let Input::File(path) = days::input(args.day, inputs) else {
+        unreachable!("only an explicit path resolves to stdin");
+    };
+    let fetched = Client::from_env(server)
+        .and_then(|client| client::fetch_cached(&client, args.day, &path, args.force));
+    match fetched {
+        Ok(true) => println!("downloaded {}", path.display()),
+        Ok(false) => println!("{} is already there", path.display()),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn submit(args: SubmitArgs, inputs: &InputOptions, server: Option<&str>) -> ExitCode {
+    let part = Part::try_from(args.part).unwrap();
+    let answer = match args.answer {
+        Some(answer) => Recorded::parse(&answer),
+        None => {
+            let Some(solution) = select(Some(args.day)).and_then(|s| s.into_iter().next()) else {
+                return ExitCode::FAILURE;
+            };
+            let Some((_, parsed)) = load(solution.as_ref(), &days::input(args.day, inputs)) else {
+                return ExitCode::FAILURE;
+            };
+            let answer = solution.solve(parsed.as_ref(), part);
+            match Recorded::from_answer(&answer) {
+                Some(answer) => answer,
+                None => {
+                    eprintln!("day {} part {part}: cannot submit {answer}", args.day);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let ledger_path = days::ledger(args.day);
+    let ledger = match Ledger::load(&ledger_path) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match ledger.part(part).check(&answer.to_answer()) {
+        Check::Candidate => {}
+        Check::Accepted => {
+            println!("{answer} was already accepted");
+            return ExitCode::SUCCESS;
+        }
+        Check::Changed { accepted } => {
+            eprintln!("not submitting {answer}, {accepted} was already accepted");
+            return ExitCode::FAILURE;
+        }
+        Check::Rejected { reason } => {
+            eprintln!("not submitting: {reason}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let response = Client::from_env(server)
+        .and_then(|client| client.submit(args.day, part, &answer.to_string()));
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("day {} part {part}: {answer} {response}", args.day);
+    let verdict = match response {
+        Response::Accepted => None,
+        Response::Rejected(verdict) => Some(verdict),
+        _ => return ExitCode::FAILURE,
+    };
+    if let Err(e) = ledger::record(&ledger_path, part, &answer, verdict) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+    if verdict.is_none() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn new(args: NewArgs) -> ExitCode {
+    let day = args.day;
+    if let Err(e) = scaffold::new_day(&days::workspace_root(), day) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+    println!("created day{day}, next:");
+    println!("  - put the puzzle input in day{day}/input.txt, or run `aoc fetch --day {day}`");
+    println!(
+        "  - put the example in day{day}/input_test.txt and its answers in input_test.expected"
+    );
+    println!("  - fill in parse_input, solve_part1 and solve_part2 in day{day}/src/lib.rs");
+    ExitCode::SUCCESS
+}
+
+fn diff(args: DiffArgs) -> ExitCode {
+    let Some(solutions) = select(args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let budget = Budget {
+        max_steps: args.max_steps,
+        timeout: Some(args.timeout),
+    };
+
+    let mut success = true;
+    for solution in solutions {
+        let day = solution.day();
+        for part in Part::ALL.into_iter().filter(|&p| solution.has_reference(p)) {
+            let checked = differential::check(
+                solution.as_ref(),
+                part,
+                args.seed,
+                args.cases,
+                args.size,
+                budget,
+            );
+            match checked {
+                Ok(0) => {
+                    println!(
+                        "day {day} part {part}: no random input to compare, see Solution::generate"
+                    );
+                    success = false;
+                }
+                Ok(compared) => println!(
+                    "day {day} part {part}: agrees with the reference on {compared} random inputs"
+                ),
+                Err(divergence) => {
+                    println!("{divergence}");
+                    success = false;
+                }
+            }
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn gen(args: GenArgs) -> ExitCode {
+    let Some(solution) = days::find(args.day) else {
+        eprintln!("no solution for day {}", args.day);
+        return ExitCode::FAILURE;
+    };
+    let mut rng = StdRng::seed_from_u64(args.seed);
+    match solution.generate(&mut rng, args.size) {
+        Some(input) => {
+            print!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("day {} has no input generator", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(args: WatchArgs, inputs: InputOptions) -> ExitCode {
+    if days::find(args.day).is_none() {
+        eprintln!("no solution for day {}", args.day);
+        return ExitCode::FAILURE;
+    }
+    let real_input = args.input.is_none() && !args.example;
+    let inputs = InputOptions {
+        path: args.input,
+        example: args.example,
+        ..inputs
+    };
+    let Input::File(input) = days::input(args.day, &inputs) else {
+        eprintln!("cannot watch stdin, give an input file");
+        return ExitCode::FAILURE;
+    };
+    match watch::watch(args.day, &input, real_input) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("cannot watch day {}: {e}", args.day);
+            ExitCode::FAILURE
+        }
+    }
+}
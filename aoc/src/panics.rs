@@ -0,0 +1,54 @@
+//! Helpers for running solutions that may panic.
+
+use std::{
+    any::Any,
+    panic,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    },
+};
+
+/// How many calls to [`silenced`] are running, on any thread.
+static SILENCED: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs `f` with the panic hook silenced, so that panics caught with `catch_unwind` and
+/// reported some other way do not also flood stderr.
+///
+/// The hook itself is never swapped out, that would drop any hook installed in the meantime,
+/// like the one `common::budget` uses. Instead a hook that holds back every panic while
+/// something is silenced is wrapped around the existing one, once.
+pub fn silenced<T>(f: impl FnOnce() -> T) -> T {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if SILENCED.load(Ordering::SeqCst) == 0 {
+                hook(info);
+            }
+        }));
+    });
+
+    /// Ends the silence even if `f` panics.
+    struct Silence;
+    impl Drop for Silence {
+        fn drop(&mut self) {
+            SILENCED.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    SILENCED.fetch_add(1, Ordering::SeqCst);
+    let _silence = Silence;
+    f()
+}
+
+/// The message a panic was raised with, `?` if it was not a string.
+pub fn message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "?"
+    }
+}
@@ -0,0 +1,239 @@
+//! Runs several days at once on a small pool of worker threads. A day that fails to load or
+//! panics is reported in its row of the summary instead of taking the other days down.
+
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{
+    budget::Budget,
+    output::{self, Record},
+    Answer, DynSolution, Input, Part,
+};
+use serde::Serialize;
+
+use crate::panics;
+
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    pub days: Vec<DayRun>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayRun {
+    pub day: u8,
+    pub input: String,
+    /// `None` if the input could not be read, see `error`.
+    pub input_sha256: Option<String>,
+    /// `None` if the input could not be read or parsed, see `error`.
+    pub parse_ns: Option<u64>,
+    pub parts: Vec<PartRun>,
+    /// Why the input could not be read or parsed, or the message parsing panicked with.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartRun {
+    pub part: u8,
+    /// Unsolved if the part is, or if it panicked or timed out.
+    pub answer: Answer,
+    pub elapsed_ns: u64,
+    /// The message the part panicked with.
+    pub panic: Option<String>,
+    /// How far the part got before running out of its budget.
+    pub timed_out: Option<String>,
+}
+
+/// Runs `parts` of every day on its input with up to `threads` days at a time, each part
+/// within `budget`. The days are returned in the order they were given.
+pub fn run_all(
+    days: &[(Box<dyn DynSolution>, Input)],
+    parts: &[Part],
+    budget: Budget,
+    threads: usize,
+) -> Summary {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(days.len()));
+    panics::silenced(|| {
+        thread::scope(|scope| {
+            for _ in 0..threads.clamp(1, days.len().max(1)) {
+                scope.spawn(|| loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some((solution, input)) = days.get(i) else {
+                        break;
+                    };
+                    let run = run_day(solution.as_ref(), input, parts, budget);
+                    runs.lock().unwrap().push((i, run));
+                });
+            }
+        })
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|(i, _)| *i);
+    Summary {
+        days: runs.into_iter().map(|(_, run)| run).collect(),
+    }
+}
+
+fn run_day(solution: &dyn DynSolution, input: &Input, parts: &[Part], budget: Budget) -> DayRun {
+    let mut run = DayRun {
+        day: solution.day(),
+        input: input.to_string(),
+        input_sha256: None,
+        parse_ns: None,
+        parts: Vec::new(),
+        error: None,
+    };
+    let text = match input.read() {
+        Ok(text) => text,
+        Err(e) => {
+            run.error = Some(format!("cannot read {input}: {e}"));
+            return run;
+        }
+    };
+
+    run.input_sha256 = Some(output::input_hash(&text));
+
+    let start = Instant::now();
+    let parsed = match panic::catch_unwind(AssertUnwindSafe(|| solution.parse(&text))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(e)) => {
+            run.error = Some(e.with_file(input.to_string()).to_string());
+            return run;
+        }
+        Err(payload) => {
+            run.error = Some(format!(
+                "parsing panicked: {}",
+                panics::message(payload.as_ref())
+            ));
+            return run;
+        }
+    };
+    run.parse_ns = Some(nanos(start.elapsed()));
+
+    for &part in parts {
+        let start = Instant::now();
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            common::budget::limit(budget, || solution.solve(parsed.as_ref(), part))
+        }));
+        let mut run_part = PartRun {
+            part: part.number(),
+            answer: Answer::Unsolved,
+            elapsed_ns: nanos(start.elapsed()),
+            panic: None,
+            timed_out: None,
+        };
+        match outcome {
+            Ok(Ok(answer)) => run_part.answer = answer,
+            Ok(Err(exceeded)) => run_part.timed_out = Some(exceeded.to_string()),
+            Err(payload) => run_part.panic = Some(panics::message(payload.as_ref()).to_string()),
+        }
+        run.parts.push(run_part);
+    }
+    run
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+impl DayRun {
+    /// Whether the input loaded and every part finished without panicking.
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+            && self
+                .parts
+                .iter()
+                .all(|p| p.panic.is_none() && p.timed_out.is_none())
+    }
+
+    /// The parts that finished, solved or not.
+    pub fn records(&self) -> Vec<Record> {
+        let Some(hash) = &self.input_sha256 else {
+            return Vec::new();
+        };
+        self.parts
+            .iter()
+            .filter(|p| p.panic.is_none() && p.timed_out.is_none())
+            .map(|p| Record {
+                day: self.day,
+                part: p.part,
+                answer: p.answer.clone(),
+                elapsed_ns: p.elapsed_ns,
+                input_sha256: hash.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Summary {
+    pub fn succeeded(&self) -> bool {
+        self.days.iter().all(DayRun::succeeded)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// One row per day with the answers and timings, then the errors and panics in full.
+    pub fn print_table(&self) {
+        println!(
+            "{:>3}  {:<12} {:<32} {:<32}",
+            "day", "parse", "part 1", "part 2"
+        );
+        let d = Duration::from_nanos;
+        for day in &self.days {
+            let parse = match day.parse_ns {
+                Some(ns) => format!("{:.2?}", d(ns)),
+                None => "failed".to_string(),
+            };
+            let mut cells = [String::new(), String::new()];
+            for part in &day.parts {
+                cells[part.part as usize - 1] = if part.panic.is_some() {
+                    "panicked".to_string()
+                } else if part.timed_out.is_some() {
+                    format!("timed out ({:.2?})", d(part.elapsed_ns))
+                } else if part.answer == Answer::Unsolved {
+                    "unsolved".to_string()
+                } else {
+                    format!("{} ({:.2?})", part.answer, d(part.elapsed_ns))
+                };
+            }
+            let [part1, part2] = cells;
+            println!("{:>3}  {parse:<12} {part1:<32} {part2:<32}", day.day);
+        }
+
+        for failure in self.failures() {
+            println!("{failure}");
+        }
+    }
+
+    /// The errors, panics and timeouts, one line each.
+    pub fn failures(&self) -> Vec<String> {
+        let mut failures = Vec::new();
+        for day in &self.days {
+            if let Some(error) = &day.error {
+                failures.push(format!("day {}: {error}", day.day));
+            }
+            for part in &day.parts {
+                if let Some(panic) = &part.panic {
+                    failures.push(format!(
+                        "day {} part {}: panicked: {panic}",
+                        day.day, part.part
+                    ));
+                }
+                if let Some(timed_out) = &part.timed_out {
+                    failures.push(format!("day {} part {}: {timed_out}", day.day, part.part));
+                }
+            }
+        }
+        failures
+    }
+}
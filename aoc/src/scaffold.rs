@@ -0,0 +1,110 @@
+//! `aoc new`: creates a `dayN` crate from the templates in `aoc/templates` and wires it into
+//! the workspace, the runner's dependencies and [`days::solutions`](crate::days::solutions).
+
+use std::{fs, path::Path};
+
+use toml_edit::{DocumentMut, InlineTable, Value};
+
+const TEMPLATES: [(&str, &str); 6] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("input.txt", ""),
+    ("input_test.txt", ""),
+    (
+        "input_test.expected",
+        include_str!("../templates/input_test.expected.tmpl"),
+    ),
+];
+
+/// Creates `root/dayN` and registers it. `root` is the workspace root.
+pub fn new_day(root: &Path, day: u8) -> Result<(), String> {
+    let dir = root.join(format!("day{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    for (name, template) in TEMPLATES {
+        let path = dir.join(name);
+        fs::write(&path, template.replace("{{day}}", &day.to_string()))
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    }
+
+    edit(&root.join("Cargo.toml"), |text| add_member(text, day))?;
+    edit(&root.join("aoc/Cargo.toml"), |text| {
+        add_dependency(text, day)
+    })?;
+    edit(&root.join("aoc/src/days.rs"), |text| {
+        add_solution(text, day)
+    })
+}
+
+fn edit(path: &Path, change: impl FnOnce(&str) -> Result<String, String>) -> Result<(), String> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    let text = change(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+    fs::write(path, text).map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+/// The day number of a `dayN` name.
+fn day_number(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.parse().ok()
+}
+
+fn add_member(manifest: &str, day: u8) -> Result<String, String> {
+    let mut document = manifest.parse::<DocumentMut>().map_err(|e| e.to_string())?;
+    let members = document["workspace"]["members"]
+        .as_array_mut()
+        .ok_or("workspace.members is not an array")?;
+    let index = members
+        .iter()
+        .position(|m| m.as_str().and_then(day_number).is_some_and(|d| d > day))
+        .unwrap_or(members.len());
+    members.insert(index, format!("day{day}"));
+    if let Some(first) = members.get(0) {
+        let prefix = first.decor().prefix().cloned();
+        if let (Some(prefix), Some(member)) = (prefix, members.get_mut(index)) {
+            member.decor_mut().set_prefix(prefix);
+        }
+    }
+    Ok(document.to_string())
+}
+
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let mut document = manifest.parse::<DocumentMut>().map_err(|e| e.to_string())?;
+    let dependencies = document["dependencies"]
+        .as_table_mut()
+        .ok_or("dependencies is not a table")?;
+    let mut path = InlineTable::new();
+    path.insert("path", Value::from(format!("../day{day}")));
+    dependencies.insert(&format!("day{day}"), Value::InlineTable(path).into());
+    Ok(document.to_string())
+}
+
+/// Adds `erase::<dayN::DayN>(),` to the list in `days.rs`, keeping the days in order.
+fn add_solution(source: &str, day: u8) -> Result<String, String> {
+    let entry = |d: u8| format!("erase::<day{d}::Day{d}>(),");
+    let lines: Vec<&str> = source.lines().collect();
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let name = line.trim().strip_prefix("erase::<")?.split("::").next()?;
+            Some((i, day_number(name)?))
+        })
+        .collect();
+    let Some(&(last, _)) = registered.last() else {
+        return Err("found no `erase::<dayN::DayN>()` to add the new day next to".to_string());
+    };
+    let index = registered
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + 1, |(i, _)| *i);
+    let indent = &lines[last][..lines[last].len() - lines[last].trim_start().len()];
+
+    let mut out: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    out.insert(index, format!("{indent}{}", entry(day)));
+    Ok(out.join("\n") + "\n")
+}
@@ -0,0 +1,248 @@
+//! Re-runs a day whenever its sources or its input change, using inotify.
+//!
+//! The runner cannot reload a day's code, so every run goes through `cargo run -p dayN`,
+//! which rebuilds the day first. Build errors go straight to the terminal. The sources are
+//! those of the day's crate and of every workspace crate it depends on, `src/` with all its
+//! subdirectories and the manifest.
+
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::Part;
+use inotify::{EventMask, Inotify, WatchDescriptor, WatchMask, Watches};
+
+use crate::{
+    days,
+    ledger::{Check, Ledger, Recorded},
+};
+
+/// How long to wait for more changes before re-running, editors often write a file in
+/// several steps.
+const SETTLE: Duration = Duration::from_millis(100);
+
+const CHANGES: WatchMask = WatchMask::CLOSE_WRITE
+    .union(WatchMask::MODIFY)
+    .union(WatchMask::CREATE)
+    .union(WatchMask::DELETE)
+    .union(WatchMask::MOVED_TO);
+
+/// Runs `day` on `input` once, then again after every change until interrupted. The answers
+/// are only checked against the ledger if `input` is the real puzzle input.
+pub fn watch(day: u8, input: &Path, real_input: bool) -> io::Result<()> {
+    // Cargo runs in the workspace root, so a relative path would mean something else there.
+    let input = &input.canonicalize()?;
+    let crates = crates(&days::day_dir(day))?;
+
+    let mut inotify = Inotify::init()?;
+    let mut watched = Watched {
+        watches: inotify.watches(),
+        dirs: HashMap::new(),
+    };
+    for dir in &crates {
+        watched.add(dir, false)?;
+        watched.add(&dir.join("src"), true)?;
+    }
+    // Editors tend to save by replacing the file, so watch the directory the input is in.
+    watched.add(input.parent().unwrap_or(Path::new("/")), false)?;
+    println!(
+        "watching {} and {}, Ctrl-C to stop",
+        crates
+            .iter()
+            .map(|dir| shorten(dir).display().to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+        input.display()
+    );
+
+    let mut previous = [None, None];
+    let mut buffer = [0; 4096];
+    loop {
+        rerun(day, input, real_input, &mut previous);
+
+        let mut changed = Vec::new();
+        while changed.is_empty() {
+            let events = inotify.read_events_blocking(&mut buffer)?;
+            changed.extend(watched.changes(events, input));
+        }
+        thread::sleep(SETTLE);
+        loop {
+            match inotify.read_events(&mut buffer) {
+                Ok(events) => changed.extend(watched.changes(events, input)),
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e),
+            }
+        }
+        changed.sort();
+        changed.dedup();
+        println!();
+        println!(
+            "changed: {}",
+            changed
+                .iter()
+                .map(|path| shorten(path).display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+/// The directory of the crate in `dir` and of every crate it depends on by path, directly or
+/// not.
+pub fn crates(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut crates = vec![dir.canonicalize()?];
+    let mut i = 0;
+    while i < crates.len() {
+        let manifest = fs::read_to_string(crates[i].join("Cargo.toml"))?;
+        let manifest: toml::Table =
+            toml::from_str(&manifest).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let dependencies = manifest.get("dependencies").and_then(|d| d.as_table());
+        for dependency in dependencies.into_iter().flat_map(|d| d.values()) {
+            let Some(path) = dependency.get("path").and_then(|p| p.as_str()) else {
+                continue;
+            };
+            let path = crates[i].join(path).canonicalize()?;
+            if !crates.contains(&path) {
+                crates.push(path);
+            }
+        }
+        i += 1;
+    }
+    Ok(crates)
+}
+
+/// Whether a change to `path` calls for a new run: a Rust source or a manifest of the
+/// watched crates, or the input. Anything else in the directories watched, like editor swap
+/// files or other inputs, is not.
+pub fn relevant(path: &Path, input: &Path) -> bool {
+    path == input
+        || path.extension().is_some_and(|e| e == "rs")
+        || path.file_name().is_some_and(|name| name == "Cargo.toml")
+}
+
+/// The directories being watched by their watch descriptors, and whether their new
+/// subdirectories are watched too.
+struct Watched {
+    watches: Watches,
+    dirs: HashMap<WatchDescriptor, (PathBuf, bool)>,
+}
+
+impl Watched {
+    /// Watches `dir`, and with `recursive` all its subdirectories, which inotify does not do
+    /// by itself.
+    fn add(&mut self, dir: &Path, recursive: bool) -> io::Result<()> {
+        let wd = self.watches.add(dir, CHANGES)?;
+        self.dirs.insert(wd, (dir.to_path_buf(), recursive));
+        if recursive {
+            for entry in fs::read_dir(dir)? {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    self.add(&entry.path(), true)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The relevant files among those changed, watching directories as they are created.
+    fn changes<'a>(
+        &mut self,
+        events: impl Iterator<Item = inotify::Event<&'a OsStr>>,
+        input: &Path,
+    ) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for event in events {
+            let (Some((dir, recursive)), Some(name)) = (self.dirs.get(&event.wd), event.name)
+            else {
+                continue;
+            };
+            let path = dir.join(name);
+            if event.mask.contains(EventMask::ISDIR) {
+                if *recursive
+                    && event
+                        .mask
+                        .intersects(EventMask::CREATE | EventMask::MOVED_TO)
+                {
+                    // It may be gone again already, then there is nothing to watch.
+                    let _ = self.add(&path, true);
+                }
+            } else if relevant(&path, input) {
+                changed.push(path);
+            }
+        }
+        changed
+    }
+}
+
+/// `path` relative to the workspace, if it is in there.
+fn shorten(path: &Path) -> &Path {
+    days::workspace_root()
+        .canonicalize()
+        .ok()
+        .and_then(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path)
+}
+
+/// Rebuilds and runs the day, then compares its answers to the previous run and the ledger.
+fn rerun(day: u8, input: &Path, real_input: bool, previous: &mut [Option<String>; 2]) {
+    let start = Instant::now();
+    let output = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["run", "--release", "--quiet", "-p"])
+        .arg(format!("day{day}"))
+        .arg("--")
+        .arg(input)
+        .current_dir(days::workspace_root())
+        .stderr(Stdio::inherit())
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(e) => {
+            println!("cannot run cargo: {e}");
+            return;
+        }
+    };
+    if !output.status.success() {
+        println!("day {day} failed ({})", output.status);
+        return;
+    }
+    println!("day {day} finished in {:.2?}", start.elapsed());
+
+    let ledger = real_input
+        .then(|| Ledger::load(&days::ledger(day)).map_err(|e| println!("{e}")))
+        .and_then(Result::ok);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for part in Part::ALL {
+        let prefix = format!("part {part}: ");
+        let Some(answer) = stdout.lines().find_map(|l| l.strip_prefix(&prefix)) else {
+            continue;
+        };
+
+        let previous = &mut previous[part.number() as usize - 1];
+        let change = match previous.as_deref() {
+            None => String::new(),
+            Some(before) if before == answer => ", unchanged".to_string(),
+            Some(before) => format!(", was {before}"),
+        };
+        let status = match &ledger {
+            _ if answer == "unsolved" => String::new(),
+            Some(ledger) => match ledger
+                .part(part)
+                .check(&Recorded::parse(answer).to_answer())
+            {
+                Check::Accepted => ", accepted".to_string(),
+                Check::Candidate => ", not yet accepted".to_string(),
+                Check::Changed { accepted } => format!(", WRONG: {accepted} was accepted"),
+                Check::Rejected { reason } => format!(", WRONG: {reason}"),
+            },
+            None => String::new(),
+        };
+        println!("part {part}: {answer}{change}{status}");
+        *previous = Some(answer.to_string());
+    }
+}
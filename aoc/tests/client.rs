@@ -0,0 +1,153 @@
+//! Runs the puzzle-server client against a stand-in server on localhost.
+
+use std::{fs, thread, time::Duration};
+
+use aoc::{
+    client::{self, Client, Response},
+    ledger::{self, Check, Ledger, Recorded, Verdict},
+};
+use common::{Answer, Part};
+use tiny_http::{Method, Server};
+
+const SESSION: &str = "53616c7465645f5f";
+
+fn page(message: &str) -> String {
+    format!("<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>")
+}
+
+/// Serves day 1: the input is "3 4", the part 1 answer is 7 and everything else is rejected.
+fn stand_in_server() -> String {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            let authorized = request.headers().iter().any(|h| {
+                h.field.equiv("Cookie") && h.value.as_str() == format!("session={SESSION}")
+            });
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+
+            let response = match (request.method(), request.url(), authorized) {
+                (_, _, false) => {
+                    tiny_http::Response::from_string("log in first").with_status_code(400)
+                }
+                (Method::Get, "/2024/day/1/input", _) => tiny_http::Response::from_string("3 4\n"),
+                (Method::Post, "/2024/day/1/answer", _) => {
+                    let message = match body.as_str() {
+                        "level=1&answer=7" => "That's the right answer! You are <em>one gold star</em> closer.",
+                        "level=1&answer=100" => "That's not the right answer; your answer is too high. Please wait one minute before trying again.",
+                        "level=1&answer=1" => "That's not the right answer; your answer is too low.",
+                        "level=1&answer=5" => "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait.",
+                        "level=2&answer=7" => "You don't seem to be solving the right level. Did you already complete it?",
+                        _ => "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+                    };
+                    tiny_http::Response::from_string(page(message))
+                }
+                _ => tiny_http::Response::from_string("not found").with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        }
+    });
+    url
+}
+
+#[test]
+fn fetches_input_once() {
+    let client = Client::new(&stand_in_server(), SESSION);
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("inputs").join("day1.txt");
+
+    assert!(client::fetch_cached(&client, 1, &path, false).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3 4\n");
+
+    fs::write(&path, "cached").unwrap();
+    assert!(!client::fetch_cached(&client, 1, &path, false).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "cached");
+    assert!(client::fetch_cached(&client, 1, &path, true).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3 4\n");
+}
+
+#[test]
+fn rejects_a_wrong_session() {
+    let client = Client::new(&stand_in_server(), "wrong");
+    assert!(client.fetch_input(1).is_err());
+}
+
+#[test]
+fn parses_submission_responses() {
+    let client = Client::new(&stand_in_server(), SESSION);
+    let submit = |part, answer| client.submit(1, part, answer).unwrap();
+
+    assert_eq!(submit(Part::One, "7"), Response::Accepted);
+    assert_eq!(
+        submit(Part::One, "100"),
+        Response::Rejected(Verdict::TooHigh)
+    );
+    assert_eq!(submit(Part::One, "1"), Response::Rejected(Verdict::TooLow));
+    assert_eq!(submit(Part::One, "6"), Response::Rejected(Verdict::Wrong));
+    assert_eq!(
+        submit(Part::One, "5"),
+        Response::Wait(Duration::from_secs(83))
+    );
+    assert_eq!(submit(Part::Two, "7"), Response::AlreadySolved);
+}
+
+#[test]
+fn records_verdicts_in_the_ledger() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ledger.toml");
+    fs::write(
+        &path,
+        "[part1]\n# kept\nrejected = [\n    { answer = 1, verdict = \"too-low\" },\n]\n",
+    )
+    .unwrap();
+
+    ledger::record(
+        &path,
+        Part::One,
+        &Recorded::Integer(100),
+        Some(Verdict::TooHigh),
+    )
+    .unwrap();
+    ledger::record(&path, Part::One, &Recorded::Integer(7), None).unwrap();
+    ledger::record(
+        &path,
+        Part::Two,
+        &Recorded::parse("abc"),
+        Some(Verdict::Wrong),
+    )
+    .unwrap();
+
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(text.contains("# kept"), "{text}");
+    assert!(
+        text.contains("    { answer = 100, verdict = \"too-high\" },\n]"),
+        "{text}"
+    );
+
+    let ledger = Ledger::load(&path).unwrap();
+    assert_eq!(
+        ledger.part(Part::One).check(&Answer::from(7)),
+        Check::Accepted
+    );
+    assert_eq!(ledger.part(Part::One).lower_bound(), Some(1));
+    assert_eq!(ledger.part(Part::One).upper_bound(), Some(100));
+    assert!(matches!(
+        ledger.part(Part::Two).check(&Answer::from("abc")),
+        Check::Rejected { .. }
+    ));
+}
+
+#[test]
+fn ledgers_mark_parts_known_failing() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("ledger.toml");
+    fs::write(&path, "[part2]\naccepted = 949\nknown-failing = true\n").unwrap();
+    let ledger = Ledger::load(&path).unwrap();
+    assert!(!ledger.part(Part::One).known_failing);
+    assert!(ledger.part(Part::Two).known_failing);
+    assert!(matches!(
+        ledger.part(Part::Two).check(&Answer::from(594)),
+        Check::Changed { .. }
+    ));
+}
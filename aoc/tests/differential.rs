@@ -0,0 +1,47 @@
+//! Differential tests: every reference implementation on random inputs, and the shrinking of
+//! a diverging input on solutions with planted bugs.
+
+use aoc::{days, differential};
+use common::{budget::Budget, erase, Part};
+use fakes::{Buggy, Stuck};
+
+mod fakes;
+
+const BUDGET: Budget = Budget {
+    max_steps: Some(10_000_000),
+    timeout: None,
+};
+
+#[test]
+fn solutions_agree_with_their_references() {
+    let mut checked = 0;
+    for solution in days::solutions() {
+        for part in Part::ALL.into_iter().filter(|&p| solution.has_reference(p)) {
+            match differential::check(solution.as_ref(), part, 1, 20, 6, BUDGET) {
+                Ok(compared) => assert!(compared > 0, "day {} part {part}", solution.day()),
+                Err(divergence) => panic!("{divergence}"),
+            }
+            checked += 1;
+        }
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn reports_the_first_divergence_shrunk() {
+    let divergence = differential::check(erase::<Buggy>().as_ref(), Part::One, 7, 50, 10, BUDGET)
+        .expect_err("the planted bug was not found");
+    assert_eq!(divergence.day, 96);
+    assert_eq!(divergence.input, "5\n");
+    assert_eq!(divergence.answer, "0");
+    assert_eq!(divergence.reference, "5");
+}
+
+#[test]
+fn shrinking_skips_inputs_that_run_out_of_budget() {
+    let divergence = differential::check(erase::<Stuck>().as_ref(), Part::One, 7, 50, 10, BUDGET)
+        .expect_err("the planted bug was not found");
+    assert_eq!(divergence.day, 95);
+    assert_eq!(divergence.input.split_whitespace().count(), 2);
+    assert_ne!(divergence.answer, divergence.reference);
+}
@@ -0,0 +1,70 @@
+//! Runs every day against its bundled examples, see `aoc::examples`.
+
+use aoc::{days, examples};
+use common::{Answer, Part};
+
+#[test]
+fn examples_match_expected_answers() {
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for solution in days::solutions() {
+        let day = solution.day();
+        let examples = examples::discover(day).unwrap();
+        if examples.is_empty() {
+            failures.push(format!("day {day}: no input_test*.txt examples"));
+        }
+
+        for example in examples {
+            let name = example.input.display();
+            let Some(expected) = example.expected else {
+                failures.push(format!(
+                    "{name}: no expected answers, add {}",
+                    examples::expected_path(&example.input).display()
+                ));
+                continue;
+            };
+            let text = std::fs::read_to_string(&example.input).unwrap();
+            let parsed = match solution.parse(&text) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    failures.push(format!("{name}: {e}"));
+                    continue;
+                }
+            };
+
+            for part in Part::ALL {
+                let Some(expected) = expected.part(part) else {
+                    continue;
+                };
+                let answer = solution.solve(parsed.as_ref(), part);
+                let correct = answer.to_string() == expected.answer;
+                match (answer, correct, expected.known_failing) {
+                    (Answer::Unsolved, _, _) => {
+                        eprintln!("{name} part {part}: unsolved, skipped")
+                    }
+                    (_, true, false) => checked += 1,
+                    (answer, false, true) => eprintln!(
+                        "{name} part {part}: known failing, got {answer}, expected {}",
+                        expected.answer
+                    ),
+                    (_, true, true) => failures.push(format!(
+                        "{name} part {part}: passes now, drop `(known failing)`"
+                    )),
+                    (answer, false, false) => failures.push(format!(
+                        "{name} part {part}: got {answer}, expected {}",
+                        expected.answer
+                    )),
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} example answers failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+    assert!(checked > 0);
+}
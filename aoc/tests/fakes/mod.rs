@@ -0,0 +1,147 @@
+//! Made-up solutions for testing the runner, each with a day of its own that no real one
+//! uses.
+
+// Every test crate includes this module and uses only some of them.
+#![allow(dead_code)]
+
+use common::{budget, Answer, ParseError, Part, Solution};
+use rand::{rngs::StdRng, Rng};
+
+/// Sums numbers, but forgets the multiples of 5.
+pub struct Buggy;
+
+impl Solution for Buggy {
+    const DAY: u8 = 96;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parsing::words(input, input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().filter(|&&n| n % 5 != 0).sum::<u64>().into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn reference(part: Part) -> Option<fn(&Self::Input) -> Answer> {
+        match part {
+            Part::One => Some(|input| input.iter().sum::<u64>().into()),
+            Part::Two => None,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        let numbers: Vec<String> = (0..size)
+            .map(|_| rng.gen_range(1..100u64).to_string())
+            .collect();
+        Some(numbers.join(" ") + "\n")
+    }
+}
+
+/// Like [`Buggy`], but never finishes part 1 on a single number, where shrinking ends up.
+pub struct Stuck;
+
+impl Solution for Stuck {
+    const DAY: u8 = 95;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Buggy::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        if input.len() == 1 {
+            loop {
+                budget::step();
+            }
+        }
+        Buggy::part1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn reference(part: Part) -> Option<fn(&Self::Input) -> Answer> {
+        Buggy::reference(part)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Buggy::generate(rng, size)
+    }
+}
+
+/// Like day 5 on an empty update: the middle page of each list, which panics on an empty one.
+pub struct Middle;
+
+impl Solution for Middle {
+    const DAY: u8 = 98;
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| match line {
+                "" => Ok(Vec::new()),
+                line => parsing::separated(input, line, ','),
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|list| list[list.len() / 2])
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// The sum of all numbers.
+pub struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 99;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parsing::words(input, input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input.iter().sum::<u64>().into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        input.iter().product::<u64>().into()
+    }
+}
+
+/// Never finishes part 1.
+pub struct Forever;
+
+impl Solution for Forever {
+    const DAY: u8 = 97;
+    type Input = ();
+
+    fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+        Ok(())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        loop {
+            budget::step();
+        }
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,39 @@
+//! Every day's input generator must give the same input for the same seed, and inputs that
+//! parse and solve. Generators are optional, except for days with a reference to check.
+
+use aoc::days;
+use common::Part;
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn generators_are_seeded() {
+    for solution in days::solutions() {
+        let generate = |seed| solution.generate(&mut StdRng::seed_from_u64(seed), 8);
+        let day = solution.day();
+        if generate(1).is_none() {
+            let referenced = Part::ALL.into_iter().any(|p| solution.has_reference(p));
+            assert!(!referenced, "day {day} has a reference but no generator");
+            continue;
+        }
+        assert_eq!(generate(1), generate(1), "day {day}");
+        assert_ne!(generate(1), generate(2), "day {day}");
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    let mut rng = StdRng::seed_from_u64(2024);
+    for solution in days::solutions() {
+        for size in 0..=8 {
+            let Some(input) = solution.generate(&mut rng, size) else {
+                break;
+            };
+            let parsed = solution
+                .parse(&input)
+                .unwrap_or_else(|e| panic!("day {} size {size}: {e}\n{input}", solution.day()));
+            for part in Part::ALL {
+                solution.solve(parsed.as_ref(), part);
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! The days are libraries, their types and helpers can be used on their own.
+
+use day13::{calculate_inverses, ProblemSet};
+use day9::{diskmap_checksum, DiskBlock};
+use grid::Vec2;
+
+#[test]
+fn claw_machine_presses() {
+    let machine = ProblemSet {
+        a: Vec2::new(94, 34),
+        b: Vec2::new(22, 67),
+        prize: Vec2::new(8400, 5400),
+    };
+    assert_eq!(calculate_inverses(&machine), Some((80, 40)));
+
+    let parallel = ProblemSet {
+        b: Vec2::new(47, 17),
+        ..machine
+    };
+    assert_eq!(calculate_inverses(&parallel), None);
+}
+
+#[test]
+fn disk_map_checksum() {
+    let disk_map = day9::parse_input("12345\n").unwrap();
+    assert!(matches!(disk_map[1], DiskBlock::Empty { gap: 2 }));
+    // 0..111....22222
+    assert_eq!(
+        diskmap_checksum(&disk_map),
+        (3 + 4 + 5) + 2 * (10 + 11 + 12 + 13 + 14)
+    );
+}
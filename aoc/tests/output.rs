@@ -0,0 +1,56 @@
+//! The machine-readable answer formats.
+
+use std::time::Duration;
+
+use common::{
+    output::{self, Format, Record},
+    Answer, Part,
+};
+
+fn record(answer: Answer) -> Record {
+    Record::new(
+        1,
+        Part::Two,
+        answer,
+        Duration::from_micros(15),
+        &output::input_hash("3   4\n"),
+    )
+}
+
+#[test]
+fn formats_round_trip_through_their_names() {
+    for name in Format::NAMES {
+        assert_eq!(name.parse::<Format>().unwrap().to_string(), name);
+    }
+    assert!("csv".parse::<Format>().is_err());
+}
+
+#[test]
+fn records_are_formatted_per_format() {
+    let hash = output::input_hash("3   4\n");
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, output::input_hash("3   4\n"));
+    assert_ne!(hash, output::input_hash("3   4"));
+
+    let solved = record(Answer::from(31u64));
+    assert_eq!(Format::Plain.format(&solved), "part 2: 31");
+    assert_eq!(
+        Format::Json.format(&solved),
+        format!(r#"{{"day":1,"part":2,"answer":31,"elapsed_ns":15000,"input_sha256":"{hash}"}}"#)
+    );
+    assert_eq!(
+        Format::Tsv.format(&solved),
+        format!("1\t2\t31\t15000\t{hash}")
+    );
+    assert_eq!(
+        Format::Tsv.header().unwrap().split('\t').count(),
+        Format::Tsv.format(&solved).split('\t').count()
+    );
+
+    let unsolved = record(Answer::Unsolved);
+    assert!(Format::Json.format(&unsolved).contains(r#""answer":null"#));
+    assert_eq!(
+        Format::Tsv.format(&unsolved),
+        format!("1\t2\t\t15000\t{hash}")
+    );
+}
@@ -0,0 +1,52 @@
+//! Silencing panics while running days keeps the hooks installed in the meantime. This is
+//! the only test in its binary, as it replaces the process-wide panic hook.
+
+use std::{
+    fs,
+    panic::{self, PanicHookInfo},
+    sync::Mutex,
+};
+
+use aoc::parallel;
+use common::{
+    budget::{self, Budget},
+    erase, Input, Part,
+};
+use fakes::Forever;
+
+mod fakes;
+
+static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn record(info: &PanicHookInfo) {
+    let message = info.payload().downcast_ref::<&str>().unwrap_or(&"?");
+    SEEN.lock().unwrap().push(message.to_string());
+}
+
+#[test]
+fn budget_unwinds_stay_quiet_after_a_silenced_run() {
+    panic::set_hook(Box::new(record));
+
+    // The first limited run installs the hook that hides budget unwinds, while the runner
+    // has silenced panics.
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.txt");
+    fs::write(&path, "\n").unwrap();
+    let days = vec![(erase::<Forever>(), Input::File(path))];
+    let budget = Budget {
+        max_steps: Some(10),
+        timeout: None,
+    };
+    let summary = parallel::run_all(&days, &[Part::One], budget, 1);
+    assert!(summary.days[0].parts[0].timed_out.is_some());
+
+    let exceeded = budget::limit(budget, || loop {
+        budget::step();
+    });
+    assert_eq!(exceeded.unwrap_err().steps, 10);
+    assert!(panic::catch_unwind(|| panic!("a real panic")).is_err());
+
+    // Copied out, a failing assertion would call the hook with the lock held.
+    let seen = SEEN.lock().unwrap().clone();
+    assert_eq!(seen, ["a real panic"]);
+}
@@ -0,0 +1,81 @@
+//! Running several days at once, where some of them panic or run out of time.
+
+use std::{fs, time::Duration};
+
+use aoc::parallel;
+use common::{budget::Budget, erase, Answer, Input, Part};
+use fakes::{Forever, Middle, Sum};
+
+mod fakes;
+
+#[test]
+fn a_panicking_day_does_not_stop_the_others() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = |name: &str, text: &str| {
+        let path = dir.path().join(name);
+        fs::write(&path, text).unwrap();
+        Input::File(path)
+    };
+    let days = vec![
+        (erase::<Middle>(), input("middle.txt", "1,2,3\n\n")),
+        (erase::<Sum>(), input("sum.txt", "2 3 4\n")),
+        (erase::<Sum>(), Input::File(dir.path().join("missing.txt"))),
+        (erase::<Middle>(), input("fine.txt", "1,2,3\n4,5,6\n")),
+    ];
+
+    let summary = parallel::run_all(&days, &Part::ALL, Budget::default(), 3);
+    assert!(!summary.succeeded());
+    let days = &summary.days;
+    assert_eq!(days.len(), 4);
+
+    assert_eq!(days[0].day, 98);
+    assert!(days[0].parts[0]
+        .panic
+        .as_ref()
+        .unwrap()
+        .contains("out of bounds"));
+    assert_eq!(days[0].parts[1].answer, Answer::Unsolved);
+    assert_eq!(days[0].parts[1].panic, None);
+
+    assert!(days[1].succeeded());
+    assert_eq!(days[1].parts[0].answer, Answer::from(9u64));
+    assert_eq!(days[1].parts[1].answer, Answer::from(24u64));
+
+    assert!(days[2].error.as_ref().unwrap().starts_with("cannot read"));
+    assert!(days[2].parts.is_empty());
+
+    assert!(days[3].succeeded());
+    assert_eq!(days[3].parts[0].answer, Answer::from(7u64));
+}
+
+#[test]
+fn a_part_over_budget_times_out() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("input.txt");
+    fs::write(&path, "\n").unwrap();
+    let days = vec![(erase::<Forever>(), Input::File(path))];
+
+    let budget = Budget {
+        max_steps: Some(1000),
+        timeout: None,
+    };
+    let summary = parallel::run_all(&days, &Part::ALL, budget, 1);
+    let day = &summary.days[0];
+    assert!(!day.succeeded());
+    assert_eq!(
+        day.parts[0]
+            .timed_out
+            .as_deref()
+            .map(|t| t.split(" (").next().unwrap()),
+        Some("timed out after 1000 steps")
+    );
+    assert_eq!(day.parts[0].panic, None);
+    assert_eq!(day.parts[1].timed_out, None);
+
+    let budget = Budget {
+        max_steps: None,
+        timeout: Some(Duration::from_millis(50)),
+    };
+    let summary = parallel::run_all(&days, &[Part::One], budget, 1);
+    assert!(summary.days[0].parts[0].timed_out.is_some());
+}
@@ -0,0 +1,43 @@
+//! Runs `aoc new` on a copy of the workspace manifests.
+
+use std::fs;
+
+use aoc::{days, scaffold};
+
+#[test]
+fn new_day_is_wired_into_the_workspace() {
+    let root = tempfile::tempdir().unwrap();
+    let root = root.path();
+    fs::create_dir_all(root.join("aoc/src")).unwrap();
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+        fs::copy(days::workspace_root().join(file), root.join(file)).unwrap();
+    }
+
+    scaffold::new_day(root, 14).unwrap();
+
+    let lib = fs::read_to_string(root.join("day14/src/lib.rs")).unwrap();
+    assert!(lib.contains("pub struct Day14;"));
+    assert!(lib.contains("const DAY: u8 = 14;"));
+    let main = fs::read_to_string(root.join("day14/src/main.rs")).unwrap();
+    assert!(main.contains("common::run::<Day14>"));
+    assert!(root.join("day14/input_test.txt").exists());
+    assert!(root.join("day14/input_test.expected").exists());
+
+    let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+    assert!(
+        workspace.contains("    \"day13\",\n    \"day14\",\n]"),
+        "{workspace}"
+    );
+    let runner = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+    assert!(
+        runner.contains("day14 = { path = \"../day14\" }"),
+        "{runner}"
+    );
+    let solutions = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+    assert!(
+        solutions.contains("erase::<day13::Day13>(),\n        erase::<day14::Day14>(),"),
+        "{solutions}"
+    );
+
+    assert!(scaffold::new_day(root, 14).is_err());
+}
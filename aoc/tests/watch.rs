@@ -0,0 +1,39 @@
+//! What `aoc watch` looks at.
+
+use std::path::Path;
+
+use aoc::{days, watch};
+
+#[test]
+fn only_sources_manifests_and_the_input_are_relevant() {
+    let input = Path::new("/inputs/day6.txt");
+    assert!(watch::relevant(input, input));
+    assert!(watch::relevant(Path::new("/aoc/day6/src/lib.rs"), input));
+    assert!(watch::relevant(
+        Path::new("/aoc/day6/src/bin/other.rs"),
+        input
+    ));
+    assert!(watch::relevant(Path::new("/aoc/grid/Cargo.toml"), input));
+
+    assert!(!watch::relevant(Path::new("/inputs/day7.txt"), input));
+    assert!(!watch::relevant(
+        Path::new("/aoc/day6/src/.lib.rs.swp"),
+        input
+    ));
+    assert!(!watch::relevant(Path::new("/aoc/day6/src/4913"), input));
+    assert!(!watch::relevant(
+        Path::new("/aoc/day6/input_test.txt"),
+        input
+    ));
+}
+
+#[test]
+fn the_crates_a_day_depends_on_are_watched() {
+    let crates = watch::crates(&days::day_dir(9)).unwrap();
+    let names: Vec<_> = crates
+        .iter()
+        .map(|dir| dir.file_name().unwrap().to_str().unwrap())
+        .collect();
+    // parsing brings in grid, common comes in through both.
+    assert_eq!(names, ["day9", "common", "parsing", "grid"]);
+}
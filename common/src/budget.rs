@@ -0,0 +1,130 @@
+//! Cooperative limits on how long a solution may run.
+//!
+//! Solutions call [`step`] in their hot loops. Inside [`limit`], once the budget is used up,
+//! the next `step` unwinds out of the solution and `limit` returns [`Exceeded`]; outside of
+//! it `step` only costs a thread-local lookup.
+
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+    time::{Duration, Instant},
+};
+
+/// How much work a single solve may do. `None` means no limit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub max_steps: Option<u64>,
+    pub timeout: Option<Duration>,
+}
+
+/// Returned by [`limit`] when the budget ran out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exceeded {
+    pub steps: u64,
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Copy)]
+struct Active {
+    budget: Budget,
+    start: Instant,
+    steps: u64,
+}
+
+thread_local! {
+    static ACTIVE: Cell<Option<Active>> = const { Cell::new(None) };
+}
+
+/// Reading the clock on every step would slow down the tightest loops, so it is only read on
+/// the first step and every this many after it. A timeout is noticed up to that many steps
+/// late, and not at all in a solution that stops calling [`step`].
+const CLOCK_INTERVAL: u64 = 1024;
+
+impl Budget {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_steps.is_none() && self.timeout.is_none()
+    }
+}
+
+/// Counts one step of work against the current budget, unwinding out of the enclosing
+/// [`limit`] instead if it is used up. With `max_steps`, that many steps are allowed, and
+/// [`Exceeded`] reports how many were taken.
+pub fn step() {
+    ACTIVE.with(|active| {
+        let Some(mut current) = active.get() else {
+            return;
+        };
+        let out_of_steps = current
+            .budget
+            .max_steps
+            .is_some_and(|max| current.steps >= max);
+        let out_of_time = current.steps % CLOCK_INTERVAL == 0
+            && current
+                .budget
+                .timeout
+                .is_some_and(|timeout| current.start.elapsed() > timeout);
+        if out_of_steps || out_of_time {
+            active.set(None);
+            panic::panic_any(Exceeded {
+                steps: current.steps,
+                elapsed: current.start.elapsed(),
+            });
+        }
+        current.steps += 1;
+        active.set(Some(current));
+    });
+}
+
+/// Runs `f` with `budget`, `Err` if it was used up before `f` returned. Other panics are
+/// passed on.
+pub fn limit<T>(budget: Budget, f: impl FnOnce() -> T) -> Result<T, Exceeded> {
+    if budget.is_unlimited() {
+        return Ok(f());
+    }
+    quiet_when_exceeded();
+
+    let outer = ACTIVE.with(|active| {
+        active.replace(Some(Active {
+            budget,
+            start: Instant::now(),
+            steps: 0,
+        }))
+    });
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ACTIVE.with(|active| active.set(outer));
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<Exceeded>() {
+            Ok(exceeded) => Err(*exceeded),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/// Keeps the panic hook from printing the unwinds of [`step`], which are not errors.
+fn quiet_when_exceeded() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !info.payload().is::<Exceeded>() {
+                hook(info);
+            }
+        }));
+    });
+}
+
+impl fmt::Display for Exceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "timed out after {} steps ({:.2?})",
+            self.steps, self.elapsed
+        )
+    }
+}
+
+impl Error for Exceeded {}
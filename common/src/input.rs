@@ -0,0 +1,108 @@
+use std::{
+    env, fmt, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming a directory with one `dayN.txt` per day, used when no inputs
+/// directory is given on the command line.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+/// Where to read a day's puzzle input from, see [`InputOptions::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+/// How the user asked for the input, before it is resolved for a particular day.
+#[derive(Debug, Clone, Default)]
+pub struct InputOptions {
+    /// An explicit path, `-` for stdin.
+    pub path: Option<PathBuf>,
+    /// Use the day's example input, `input_test.txt`.
+    pub example: bool,
+    /// Directory with one `dayN.txt` per day.
+    pub inputs_dir: Option<PathBuf>,
+}
+
+impl InputOptions {
+    /// Picks the input for `day`, whose crate lives in `day_dir`, in order of preference:
+    ///
+    /// 1. the explicit path, stdin if it is `-`,
+    /// 2. `day_dir/input_test.txt` with `example`,
+    /// 3. `dayN.txt` in the inputs directory, or in `$AOC_INPUTS`,
+    /// 4. `day_dir/input.txt`.
+    pub fn resolve(&self, day: u8, day_dir: &Path) -> Input {
+        if let Some(path) = &self.path {
+            return if path == Path::new("-") {
+                Input::Stdin
+            } else {
+                Input::File(path.clone())
+            };
+        }
+        if self.example {
+            return Input::File(day_dir.join("input_test.txt"));
+        }
+        let inputs_dir = self
+            .inputs_dir
+            .clone()
+            .or_else(|| env::var_os(INPUTS_ENV).map(PathBuf::from));
+        match inputs_dir {
+            Some(dir) => Input::File(dir.join(format!("day{day}.txt"))),
+            None => Input::File(day_dir.join("input.txt")),
+        }
+    }
+
+    /// Parses the arguments of a day's own binary: `[--example] [--inputs DIR] [INPUT]`.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<InputOptions, String> {
+        let mut options = InputOptions::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--example" => options.example = true,
+                "--inputs" => {
+                    let dir = args.next().ok_or("--inputs needs a directory")?;
+                    options.inputs_dir = Some(dir.into());
+                }
+                flag if flag.starts_with("--") => return Err(format!("unknown option {flag}")),
+                path if options.path.is_none() => options.path = Some(path.into()),
+                extra => return Err(format!("unexpected argument {extra}")),
+            }
+        }
+        if options.example && options.path.is_some() {
+            return Err("--example cannot be used with an input path".to_string());
+        }
+        Ok(options)
+    }
+}
+
+impl Input {
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+            Input::File(path) => fs::read_to_string(path),
+        }
+    }
+
+    /// The input as a stream, for inputs too large to [`read`](Input::read) at once.
+    pub fn open(&self) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+            Input::File(path) => Ok(Box::new(BufReader::new(fs::File::open(path)?))),
+        }
+    }
+}
+
+/// The name used in error messages, `<stdin>` or the path.
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
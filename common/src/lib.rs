@@ -0,0 +1,13 @@
+mod answer;
+pub mod budget;
+mod input;
+mod logger;
+pub mod output;
+mod parse_error;
+mod solution;
+
+pub use answer::Answer;
+pub use input::{Input, InputOptions, INPUTS_ENV};
+pub use logger::{init_logging, level, print_answer};
+pub use parse_error::ParseError;
+pub use solution::{erase, run, DynSolution, Part, Solution};
@@ -0,0 +1,63 @@
+//! Diagnostics go through the `log` macros to stderr, answers go to stdout with
+//! [`print_answer`], so scripts can read the answers without the traces getting in the way.
+
+use std::{fmt::Display, io::Write};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::Part;
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let level = match record.level() {
+            Level::Error => "error",
+            Level::Warn => "warning",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        let _ = writeln!(
+            std::io::stderr().lock(),
+            "{level} [{}]: {}",
+            record.target(),
+            record.args()
+        );
+    }
+
+    fn flush(&self) {}
+}
+
+/// The level for `-q` and a count of `-v` flags: errors only when quiet, warnings by default,
+/// then info, debug and trace for each `-v`.
+pub fn level(quiet: bool, verbose: u8) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Error;
+    }
+    match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Sends log records at or above `level` to stderr. Only the first call has an effect.
+pub fn init_logging(level: LevelFilter) {
+    if log::set_logger(&StderrLogger).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+/// Writes an answer to stdout as `part N: answer`, the one line format scripts can rely on.
+pub fn print_answer(part: Part, answer: impl Display) {
+    println!("part {part}: {answer}");
+}
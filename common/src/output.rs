@@ -0,0 +1,119 @@
+//! Answers in a form scripts can read: one [`Record`] per solved part, written as plain
+//! `part N: answer` lines, JSON lines or tab-separated values.
+
+use std::{fmt, str::FromStr, time::Duration};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{Answer, Part};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// `part 1: 1234`, as printed by [`print_answer`](crate::print_answer).
+    #[default]
+    Plain,
+    /// One JSON object per line.
+    Json,
+    /// Tab-separated values with a header line.
+    Tsv,
+}
+
+/// The answer to one part, with what it was computed from and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// `null` in JSON and empty in TSV if the part is unsolved.
+    pub answer: Answer,
+    pub elapsed_ns: u64,
+    /// SHA-256 of the input, in hex.
+    pub input_sha256: String,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, answer: Answer, elapsed: Duration, input_sha256: &str) -> Self {
+        Record {
+            day,
+            part: part.number(),
+            answer,
+            elapsed_ns: elapsed.as_nanos() as u64,
+            input_sha256: input_sha256.to_string(),
+        }
+    }
+}
+
+/// The hex SHA-256 of a puzzle input, to tell which input an answer belongs to.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+impl Format {
+    pub const NAMES: [&'static str; 3] = ["plain", "json", "tsv"];
+
+    /// The line to print before the records, if the format has one.
+    pub fn header(self) -> Option<&'static str> {
+        match self {
+            Format::Tsv => Some("day\tpart\tanswer\telapsed_ns\tinput_sha256"),
+            Format::Plain | Format::Json => None,
+        }
+    }
+
+    /// `record` as a single line, without the line break.
+    pub fn format(self, record: &Record) -> String {
+        match self {
+            Format::Plain => format!("part {}: {}", record.part, record.answer),
+            Format::Json => serde_json::to_string(record).unwrap(),
+            Format::Tsv => {
+                let answer = match &record.answer {
+                    Answer::Unsolved => String::new(),
+                    answer => answer.to_string().replace(['\t', '\n'], " "),
+                };
+                format!(
+                    "{}\t{}\t{answer}\t{}\t{}",
+                    record.day, record.part, record.elapsed_ns, record.input_sha256
+                )
+            }
+        }
+    }
+
+    /// Prints the header and the records to stdout.
+    pub fn print(self, records: &[Record]) {
+        if let Some(header) = self.header() {
+            println!("{header}");
+        }
+        for record in records {
+            println!("{}", self.format(record));
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => Err(format!(
+                "unknown format {s}, expected one of {}",
+                Format::NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Plain => "plain",
+            Format::Json => "json",
+            Format::Tsv => "tsv",
+        };
+        write!(f, "{name}")
+    }
+}
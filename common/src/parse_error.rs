@@ -0,0 +1,134 @@
+use std::{error::Error, fmt};
+
+/// Returned by [`Solution::parse`](crate::Solution::parse) when the puzzle input is malformed.
+///
+/// Most errors point at the offending text in the input, see [`ParseError::at`]. The file name
+/// is not known to the parsers, the caller attaches it with [`ParseError::with_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    file: Option<String>,
+    location: Option<Location>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Location {
+    /// 1-based line number.
+    line: usize,
+    /// 1-based column, counted in characters.
+    column: usize,
+    text: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// An error that cannot be pinned to a location, like something missing from the input.
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            file: None,
+            location: None,
+        }
+    }
+
+    /// An error about `text`, which must be a slice of `input` (as returned by `lines`,
+    /// `split_whitespace`, regex captures and so on) so that its position can be found.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let mut error = ParseError::new(message);
+        let start = (text.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        if start > input.len() || start + text.len() > input.len() {
+            debug_assert!(false, "{text:?} is not part of the input");
+            return error;
+        }
+
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+        error.location = Some(Location {
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            text: text.to_string(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        });
+        error
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.location.as_ref().map(|l| l.line)
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.location.as_ref().map(|l| l.column)
+    }
+
+    /// The offending text.
+    pub fn text(&self) -> Option<&str> {
+        self.location.as_ref().map(|l| l.text.as_str())
+    }
+
+    /// Formats the error with the offending line and a caret underneath the offending text:
+    ///
+    /// ```text
+    /// error: cannot parse 'x' as a digit
+    ///  --> day10/input.txt:3:5
+    ///   |
+    /// 3 | 0123x567
+    ///   |     ^
+    /// ```
+    pub fn render(&self) -> String {
+        let mut out = format!("error: {}\n", self.message);
+        let Some(location) = &self.location else {
+            if let Some(file) = &self.file {
+                out.push_str(&format!(" --> {file}\n"));
+            }
+            return out;
+        };
+
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = " ".repeat(location.line.to_string().len());
+        let width = location.text.lines().next().unwrap_or("").chars().count();
+        let underline = "^".repeat(width.max(1));
+        out.push_str(&format!(
+            "{gutter}--> {file}:{}:{}\n",
+            location.line, location.column
+        ));
+        out.push_str(&format!("{gutter} |\n"));
+        out.push_str(&format!("{} | {}\n", location.line, location.source_line));
+        out.push_str(&format!(
+            "{gutter} | {}{underline}\n",
+            " ".repeat(location.column - 1)
+        ));
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, "{}:{}:", location.line, location.column)?;
+        }
+        if self.file.is_some() || self.location.is_some() {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,195 @@
+use std::{any::Any, env, fmt, marker::PhantomData, path::Path, process, time::Instant};
+
+use rand::rngs::StdRng;
+
+use crate::{
+    init_logging, level,
+    output::{input_hash, Format, Record},
+    Answer, InputOptions, ParseError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        match v {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(v),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day's puzzle: how to parse its input and how to solve both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// A slower implementation of `part` that is easier to trust. `aoc diff` checks the real
+    /// one against it on inputs from [`Solution::generate`].
+    fn reference(_part: Part) -> Option<fn(&Self::Input) -> Answer> {
+        None
+    }
+
+    /// A random puzzle input, `size` scales it (the number of lines, the side of a map, ...).
+    fn generate(_rng: &mut StdRng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe version of [`Solution`] so that days with different input types can be
+/// stored and run side by side. Create one with [`erase`].
+pub trait DynSolution: Send + Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solves `part` for an input previously returned by [`DynSolution::parse`].
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer;
+
+    fn has_reference(&self, part: Part) -> bool;
+
+    /// Solves `part` with [`Solution::reference`], `None` if there is none.
+    fn solve_reference(&self, input: &dyn Any, part: Part) -> Option<Answer>;
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String>;
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S> DynSolution for Erased<S>
+where
+    S: Solution,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Answer {
+        let input = downcast::<S>(input);
+        match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        }
+    }
+
+    fn has_reference(&self, part: Part) -> bool {
+        S::reference(part).is_some()
+    }
+
+    fn solve_reference(&self, input: &dyn Any, part: Part) -> Option<Answer> {
+        S::reference(part).map(|reference| reference(downcast::<S>(input)))
+    }
+
+    fn generate(&self, rng: &mut StdRng, size: usize) -> Option<String> {
+        S::generate(rng, size)
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was not parsed by this solution")
+}
+
+pub fn erase<S>() -> Box<dyn DynSolution>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    Box::new(Erased::<S>(PhantomData))
+}
+
+/// Entry point of a day's own binary, whose crate lives in `day_dir`. The input is resolved
+/// from the command line arguments, see [`InputOptions::from_args`]; `-q`, `-v` and `-vv`
+/// set the log level and `--format` how the answers are printed, see [`Format`].
+pub fn run<S: Solution>(day_dir: &str) {
+    let usage = |error: String| -> ! {
+        eprintln!("{error}");
+        eprintln!(
+            "usage: day{} [-q | -v | -vv] [--format plain|json|tsv] [--example] [--inputs DIR] [INPUT | -]",
+            S::DAY
+        );
+        process::exit(2);
+    };
+
+    let (mut quiet, mut verbose, mut format) = (false, 0, Ok(Format::Plain));
+    let mut args = env::args().skip(1);
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-q" => quiet = true,
+            "-v" | "-vv" | "-vvv" => verbose += arg.len() as u8 - 1,
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or_else(|| "--format needs one of plain, json, tsv".to_string())
+                    .and_then(|f| f.parse());
+            }
+            _ => rest.push(arg),
+        }
+    }
+    let format = format.unwrap_or_else(|e| usage(e));
+    let options = InputOptions::from_args(rest.into_iter()).unwrap_or_else(|e| usage(e));
+    init_logging(level(quiet, verbose));
+
+    let source = options.resolve(S::DAY, Path::new(day_dir));
+    let text = source.read().unwrap_or_else(|e| {
+        eprintln!("cannot read {source}: {e}");
+        process::exit(1);
+    });
+    let input = S::parse(&text).unwrap_or_else(|e| {
+        eprint!("{}", e.with_file(source.to_string()).render());
+        process::exit(1);
+    });
+
+    let hash = input_hash(&text);
+    if let Some(header) = format.header() {
+        println!("{header}");
+    }
+    for part in Part::ALL {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&input),
+            Part::Two => S::part2(&input),
+        };
+        let record = Record::new(S::DAY, part, answer, start.elapsed(), &hash);
+        println!("{}", format.format(&record));
+    }
+}
@@ -0,0 +1,56 @@
+//! Day 1 for lists too large to fit in memory, see `day1::external`.
+
+use std::{env, path::Path, process};
+
+use common::{print_answer, Input, InputOptions, Part, Solution};
+use day1::{external, Day1, Id};
+use num_bigint::BigInt;
+
+fn main() {
+    let usage = |error: String| -> ! {
+        eprintln!("{error}");
+        eprintln!("usage: external [--run-len PAIRS] [--example] [--inputs DIR] [INPUT | -]");
+        process::exit(2);
+    };
+
+    let mut run_len = Ok(external::DEFAULT_RUN_LEN);
+    let mut args = env::args().skip(1);
+    let mut rest = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--run-len" => {
+                run_len = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| "--run-len needs a positive number of pairs".to_string());
+            }
+            _ => rest.push(arg),
+        }
+    }
+    let run_len = run_len.unwrap_or_else(|e| usage(e));
+    let options = InputOptions::from_args(rest.into_iter()).unwrap_or_else(|e| usage(e));
+
+    let source = options.resolve(Day1::DAY, Path::new(env!("CARGO_MANIFEST_DIR")));
+    // In `i64`, or again in `BigInt` if an ID or a total does not fit. Stdin cannot be read
+    // twice, so it goes straight to `BigInt`.
+    let result = match source {
+        Input::Stdin => reconcile::<BigInt>(&source, run_len),
+        Input::File(_) => match reconcile::<i64>(&source, run_len) {
+            Err(external::Error::Overflow(_)) => reconcile::<BigInt>(&source, run_len),
+            result => result,
+        },
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("{source}: {e}");
+        process::exit(1);
+    });
+}
+
+/// Prints both answers, computed in `T`.
+fn reconcile<T: Id>(source: &Input, run_len: usize) -> Result<(), external::Error> {
+    let totals = external::reconcile::<T>(source.open()?, run_len)?;
+    print_answer(Part::One, totals.distance);
+    print_answer(Part::Two, totals.similarity);
+    Ok(())
+}
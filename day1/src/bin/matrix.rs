@@ -0,0 +1,55 @@
+//! Day 1's metrics for every pair of columns of a list with any number of them, see
+//! `day1::columns`.
+
+use std::{env, path::Path, process};
+
+use common::{InputOptions, Solution};
+use day1::{
+    columns::{self, Table},
+    Day1, Id, Overflow,
+};
+use num_bigint::BigInt;
+
+fn main() {
+    let usage = |error: String| -> ! {
+        eprintln!("{error}");
+        eprintln!("usage: matrix [--header] [--example] [--inputs DIR] [INPUT | -]");
+        process::exit(2);
+    };
+
+    let mut header = false;
+    let mut rest = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--header" => header = true,
+            _ => rest.push(arg),
+        }
+    }
+    let options = InputOptions::from_args(rest.into_iter()).unwrap_or_else(|e| usage(e));
+
+    let source = options.resolve(Day1::DAY, Path::new(env!("CARGO_MANIFEST_DIR")));
+    let text = source.read().unwrap_or_else(|e| {
+        eprintln!("cannot read {source}: {e}");
+        process::exit(1);
+    });
+    // In `i64`, or in `BigInt` if an ID or a metric does not fit.
+    let report = match columns::parse_table::<i64>(&text, header) {
+        Ok(table) => report(&table).or_else(|Overflow| report(&table.widen())),
+        Err(narrow) => match columns::parse_table::<BigInt>(&text, header) {
+            Ok(table) => report(&table),
+            Err(_) => {
+                eprint!("{}", narrow.with_file(source.to_string()).render());
+                process::exit(1);
+            }
+        },
+    };
+    print!("{}", report.expect("a BigInt does not overflow"));
+}
+
+fn report<T: Id>(table: &Table<T>) -> Result<String, Overflow> {
+    Ok(format!(
+        "distance\n{}\nsimilarity\n{}",
+        table.distances()?,
+        table.similarities()?
+    ))
+}
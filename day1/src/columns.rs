@@ -0,0 +1,140 @@
+//! Location lists with any number of columns, compared pairwise.
+//!
+//! Every pair of columns gets the two puzzle metrics, the total distance of
+//! [`calculate_distances`] and the similarity score of [`calculate_similarity`], reported as
+//! a matrix with one row and one column per column of the input. Both metrics are symmetric,
+//! and the diagonal compares a column with itself. Like those, they are computed in the ID
+//! type and report when they do not fit it.
+
+use std::{fmt, str::FromStr};
+
+use common::ParseError;
+use num_bigint::BigInt;
+
+use crate::{calculate_distances, calculate_similarity, widen, Id, Overflow};
+
+/// Columns of location IDs, with their names if the input had a header line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table<T> {
+    pub headers: Option<Vec<String>>,
+    pub columns: Vec<Vec<T>>,
+}
+
+/// One metric for every pair of columns: `values[i][j]` compares column `i` with column `j`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    pub names: Vec<String>,
+    pub values: Vec<Vec<T>>,
+}
+
+/// Parses whitespace-separated columns of IDs. With `header`, the first line names the
+/// columns instead. Every line must have as many columns as the first one.
+pub fn parse_table<T: FromStr>(input: &str, header: bool) -> Result<Table<T>, ParseError> {
+    let mut lines = input.lines();
+    let headers = if header {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::new("expected a header line"))?;
+        Some(
+            line.split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>(),
+        )
+    } else {
+        None
+    };
+
+    let mut width = headers.as_ref().map(Vec::len);
+    let mut columns: Vec<Vec<T>> = Vec::new();
+    columns.resize_with(width.unwrap_or(0), Vec::new);
+    for line in lines {
+        let row: Vec<T> = parsing::words(input, line)?;
+        let expected = *width.get_or_insert(row.len());
+        if row.len() != expected || expected == 0 {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected {expected} columns, found {}", row.len()),
+            ));
+        }
+        columns.resize_with(expected, Vec::new);
+        for (column, id) in columns.iter_mut().zip(row) {
+            column.push(id);
+        }
+    }
+    Ok(Table { headers, columns })
+}
+
+impl<T: Id> Table<T> {
+    /// The header of each column, or its 1-based number without a header line.
+    pub fn names(&self) -> Vec<String> {
+        match &self.headers {
+            Some(headers) => headers.clone(),
+            None => (1..=self.columns.len()).map(|i| i.to_string()).collect(),
+        }
+    }
+
+    /// The total distance between every pair of columns.
+    pub fn distances(&self) -> Result<Matrix<T>, Overflow> {
+        self.matrix(|a, b| calculate_distances(a.to_vec(), b.to_vec()))
+    }
+
+    /// The similarity score of every pair of columns.
+    pub fn similarities(&self) -> Result<Matrix<T>, Overflow> {
+        self.matrix(|a, b| calculate_similarity(a.to_vec(), b.to_vec()))
+    }
+
+    fn matrix(
+        &self,
+        metric: impl Fn(&[T], &[T]) -> Result<T, Overflow>,
+    ) -> Result<Matrix<T>, Overflow> {
+        let values = self
+            .columns
+            .iter()
+            .map(|a| self.columns.iter().map(|b| metric(a, b)).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Matrix {
+            names: self.names(),
+            values,
+        })
+    }
+}
+
+impl<T: Clone + Into<BigInt>> Table<T> {
+    /// The same table in `BigInt`, for when a metric does not fit `T`.
+    pub fn widen(&self) -> Table<BigInt> {
+        Table {
+            headers: self.headers.clone(),
+            columns: self.columns.iter().map(|column| widen(column)).collect(),
+        }
+    }
+}
+
+/// The matrix as a table, with the column names along the top and down the side.
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = self.names.iter().map(String::len).max().unwrap_or(0);
+        let width = self
+            .values
+            .iter()
+            .flatten()
+            .map(|v| v.to_string().len())
+            .chain(self.names.iter().map(String::len))
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "{:label$}", "")?;
+        for name in &self.names {
+            write!(f, "  {name:>width$}")?;
+        }
+        writeln!(f)?;
+        for (name, row) in self.names.iter().zip(&self.values) {
+            write!(f, "{name:<label$}")?;
+            for value in row {
+                write!(f, "  {value:>width$}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
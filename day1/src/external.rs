@@ -0,0 +1,275 @@
+//! External-memory mode for location lists too large to hold in memory.
+//!
+//! The lists are read in runs of at most `run_len` pairs. Each column of a run is sorted and
+//! spilled to a temporary file, then the runs are merged back into one sorted stream per
+//! column. That is all both parts need: the distance pairs the two streams up in order, the
+//! similarity joins them on equal IDs. Only one run is held in memory at a time, plus a read
+//! buffer for each file being merged.
+//!
+//! The IDs are written to the runs in decimal, one per line, so that any [`Id`] type works.
+//! Like the in-memory metrics, both are computed in that type, and an ID or a total that
+//! does not fit it is reported as `Overflow`.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    error, fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::PathBuf,
+};
+
+use common::ParseError;
+use num_bigint::BigInt;
+use tempfile::TempDir;
+
+use crate::{parse_pair, Id, Overflow};
+
+/// Pairs per run unless told otherwise.
+pub const DEFAULT_RUN_LEN: usize = 1 << 20;
+
+/// Most runs merged at once. Beyond that, groups of runs are first merged into longer runs,
+/// so the number of open files stays bounded however long the lists are.
+const MAX_FAN_IN: usize = 64;
+
+/// Both answers, the same as `calculate_distances` and `calculate_similarity` give for lists
+/// that fit in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Totals<T> {
+    pub distance: T,
+    pub similarity: T,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Overflow(Overflow),
+    /// A line that is not two location IDs.
+    Parse {
+        /// 1-based line number.
+        line: usize,
+        error: ParseError,
+    },
+}
+
+/// Computes the total distance and the similarity score of the lists in `input`, sorting
+/// them in runs of `run_len` pairs on disk.
+pub fn reconcile<T: Id>(input: impl BufRead, run_len: usize) -> Result<Totals<T>, Error> {
+    let mut spill = Spill::new()?;
+    let (left, right) = spill_runs::<T>(input, run_len.max(1), &mut spill)?;
+    let left = reduce::<T>(left, &mut spill)?;
+    let right = reduce::<T>(right, &mut spill)?;
+
+    let distance = total_distance(Merge::<T>::open(&left)?, Merge::open(&right)?)?;
+    let similarity = similarity(Merge::open(&left)?, Merge::open(&right)?)?;
+    Ok(Totals {
+        distance,
+        similarity,
+    })
+}
+
+/// The temporary directory the runs are written to, removed with everything in it when
+/// dropped.
+struct Spill {
+    dir: TempDir,
+    runs: usize,
+}
+
+impl Spill {
+    fn new() -> io::Result<Self> {
+        Ok(Spill {
+            dir: tempfile::tempdir()?,
+            runs: 0,
+        })
+    }
+
+    /// Writes `ids`, which must be sorted, to a new run file.
+    fn write<T: Id>(
+        &mut self,
+        ids: impl IntoIterator<Item = io::Result<T>>,
+    ) -> io::Result<PathBuf> {
+        self.runs += 1;
+        let path = self.dir.path().join(format!("run{}", self.runs));
+        let mut out = BufWriter::new(File::create(&path)?);
+        for id in ids {
+            writeln!(out, "{}", id?)?;
+        }
+        out.flush()?;
+        Ok(path)
+    }
+}
+
+/// Reads the pairs and spills them in sorted runs, one list of runs per column.
+fn spill_runs<T: Id>(
+    input: impl BufRead,
+    run_len: usize,
+    spill: &mut Spill,
+) -> Result<(Vec<PathBuf>, Vec<PathBuf>), Error> {
+    let (mut left_runs, mut right_runs) = (Vec::new(), Vec::new());
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    let mut flush = |left: &mut Vec<T>, right: &mut Vec<T>| -> io::Result<()> {
+        left.sort_unstable();
+        right.sort_unstable();
+        left_runs.push(spill.write(left.drain(..).map(Ok))?);
+        right_runs.push(spill.write(right.drain(..).map(Ok))?);
+        Ok(())
+    };
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let (l, r) = parse_pair(&line, &line).map_err(|error| {
+            // An ID too large for `T` is an overflow like any other.
+            match parse_pair::<BigInt>(&line, &line) {
+                Ok(_) => Error::Overflow(Overflow),
+                Err(_) => Error::Parse { line: i + 1, error },
+            }
+        })?;
+        left.push(l);
+        right.push(r);
+        if left.len() == run_len {
+            flush(&mut left, &mut right)?;
+        }
+    }
+    if !left.is_empty() {
+        flush(&mut left, &mut right)?;
+    }
+    Ok((left_runs, right_runs))
+}
+
+/// Merges groups of runs until there are few enough to merge all at once.
+fn reduce<T: Id>(mut runs: Vec<PathBuf>, spill: &mut Spill) -> io::Result<Vec<PathBuf>> {
+    while runs.len() > MAX_FAN_IN {
+        let mut merged = Vec::with_capacity(runs.len().div_ceil(MAX_FAN_IN));
+        for group in runs.chunks(MAX_FAN_IN) {
+            let mut merge = Merge::<T>::open(group)?;
+            merged.push(spill.write(std::iter::from_fn(|| merge.next().transpose()))?);
+            for run in group {
+                fs::remove_file(run)?;
+            }
+        }
+        runs = merged;
+    }
+    Ok(runs)
+}
+
+/// The smallest-first merge of sorted runs.
+struct Merge<T> {
+    readers: Vec<BufReader<File>>,
+    /// The next ID of every run that is not used up, with the index of its reader.
+    heads: BinaryHeap<Reverse<(T, usize)>>,
+    line: String,
+}
+
+impl<T: Id> Merge<T> {
+    fn open(runs: &[PathBuf]) -> io::Result<Self> {
+        let mut merge = Merge {
+            readers: Vec::with_capacity(runs.len()),
+            heads: BinaryHeap::with_capacity(runs.len()),
+            line: String::new(),
+        };
+        for (i, run) in runs.iter().enumerate() {
+            merge.readers.push(BufReader::new(File::open(run)?));
+            merge.advance(i)?;
+        }
+        Ok(merge)
+    }
+
+    fn peek(&self) -> Option<&T> {
+        self.heads.peek().map(|Reverse((id, _))| id)
+    }
+
+    fn next(&mut self) -> io::Result<Option<T>> {
+        let Some(Reverse((id, i))) = self.heads.pop() else {
+            return Ok(None);
+        };
+        self.advance(i)?;
+        Ok(Some(id))
+    }
+
+    /// Reads the next ID of run `i` into the heads, if it has one.
+    fn advance(&mut self, i: usize) -> io::Result<()> {
+        self.line.clear();
+        if self.readers[i].read_line(&mut self.line)? == 0 {
+            return Ok(());
+        }
+        let id =
+            self.line.trim_end().parse().map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidData, "a run has a malformed ID")
+            })?;
+        self.heads.push(Reverse((id, i)));
+        Ok(())
+    }
+
+    /// Skips over every `id` at the front and returns how many there were.
+    fn count(&mut self, id: &T) -> io::Result<usize> {
+        let mut count = 0;
+        while self.peek() == Some(id) {
+            self.next()?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+fn total_distance<T: Id>(mut left: Merge<T>, mut right: Merge<T>) -> Result<T, Error> {
+    let mut total = T::zero();
+    while let (Some(l), Some(r)) = (left.next()?, right.next()?) {
+        total = l
+            .clone()
+            .max(r.clone())
+            .checked_sub(&l.min(r))
+            .and_then(|distance| total.checked_add(&distance))
+            .ok_or(Overflow)?;
+    }
+    Ok(total)
+}
+
+fn similarity<T: Id>(mut left: Merge<T>, mut right: Merge<T>) -> Result<T, Error> {
+    let mut total = T::zero();
+    while let Some(id) = left.peek().cloned() {
+        let in_left = left.count(&id)?;
+        while right.peek().is_some_and(|r| *r < id) {
+            right.next()?;
+        }
+        let in_right = right.count(&id)?;
+        total = T::from_usize(in_left)
+            .and_then(|count| id.checked_mul(&count))
+            .zip(T::from_usize(in_right))
+            .and_then(|(score, count)| score.checked_mul(&count))
+            .and_then(|score| total.checked_add(&score))
+            .ok_or(Overflow)?;
+    }
+    Ok(total)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Overflow(e) => write!(f, "{e}"),
+            Error::Parse { line, error } => write!(f, "line {line}: {}", error.message()),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Overflow(e) => Some(e),
+            Error::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<Overflow> for Error {
+    fn from(e: Overflow) -> Self {
+        Error::Overflow(e)
+    }
+}
@@ -0,0 +1,18 @@
+//! Random location lists.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// `size` pairs of 5 digit location IDs. Both lists draw from the same pool of IDs so that
+/// some of them repeat, or the similarity would almost always be 0.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let pool: Vec<i32> = (0..size.max(1))
+        .map(|_| rng.gen_range(10000..100000))
+        .collect();
+    (0..size)
+        .map(|_| {
+            let left = pool.choose(rng).unwrap();
+            let right = pool.choose(rng).unwrap();
+            format!("{left}   {right}\n")
+        })
+        .collect()
+}
@@ -0,0 +1,47 @@
+//! Location IDs of any integer type, with arithmetic that reports overflow.
+
+use std::{error::Error, fmt, hash::Hash, str::FromStr};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, FromPrimitive, Zero};
+
+/// An integer type the metrics can be computed in, like `i32`, `i64`, `i128` or
+/// `num_bigint::BigInt`. The answers have the same type as the IDs.
+pub trait Id:
+    Clone
+    + Ord
+    + Hash
+    + FromStr
+    + fmt::Display
+    + Zero
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + FromPrimitive
+{
+}
+
+impl<T> Id for T where
+    T: Clone
+        + Ord
+        + Hash
+        + FromStr
+        + fmt::Display
+        + Zero
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + FromPrimitive
+{
+}
+
+/// A metric does not fit the ID type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the result does not fit the integer type")
+    }
+}
+
+impl Error for Overflow {}
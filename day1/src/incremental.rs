@@ -0,0 +1,464 @@
+//! Both metrics kept up to date while pairs are inserted and removed, for reconciling lists
+//! that change over time without sorting them again.
+//!
+//! The similarity score only depends on how often each ID occurs in either list, so every
+//! update changes it by a single term.
+//!
+//! The distance pairs up the lists in sorted order, and one insertion can shift every pair
+//! after it. Instead it is computed from the counts: if `D(x)` is the number of left IDs up
+//! to `x` minus the number of right IDs up to `x`, the total distance of two lists of equal
+//! length is the sum of `|D(x)|` over all integers `x`. Inserting the pair `(l, r)` adds 1
+//! to `D` on `l..r`, or subtracts 1 on `r..l`, which changes the sum by the length of that
+//! range where `D` was non-negative minus the length where it was negative.
+//!
+//! Counting those lengths is what makes the distance expensive, since `D` can change sign
+//! at every ID and a single update can flip all of them. That rules out a Fenwick or
+//! segment tree over the IDs: a node would need the lengths below every level of `D` it
+//! spans, not a fixed summary, and those do not combine in logarithmic time. `Distance`
+//! keeps the `n` IDs in blocks of about `√n` instead, each with its lengths sorted by `D`,
+//! so an update costs `O(log n)` per block it covers and `O(√n log n)` for the at most two
+//! blocks it covers partly, `O(√n log n)` in total whatever the values of `D`. The
+//! similarity takes expected constant time. Both use memory proportional to the distinct
+//! IDs in the lists.
+
+use std::{collections::HashMap, ops::Range};
+
+use common::budget;
+
+use crate::{Id, Overflow};
+
+/// The two lists so far, with their total distance and similarity score.
+#[derive(Debug, Clone)]
+pub struct Incremental<T> {
+    distance: Distance<T>,
+    /// How often each ID occurs in the left and in the right list, without IDs that do not.
+    counts: HashMap<T, [i64; 2]>,
+    similarity: T,
+    len: usize,
+}
+
+impl<T: Id> Incremental<T> {
+    pub fn new() -> Self {
+        Incremental {
+            distance: Distance::new(),
+            counts: HashMap::new(),
+            similarity: T::zero(),
+            len: 0,
+        }
+    }
+
+    /// The lists made of `pairs`, or `Overflow` if a metric does not fit `T`.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (T, T)>) -> Result<Self, Overflow> {
+        let mut incremental = Incremental::new();
+        for (left, right) in pairs {
+            incremental.insert(left, right)?;
+        }
+        Ok(incremental)
+    }
+
+    /// Adds `left` to the left list and `right` to the right one. If either metric would no
+    /// longer fit `T`, nothing changes and `Overflow` is returned.
+    pub fn insert(&mut self, left: T, right: T) -> Result<(), Overflow> {
+        let similarity = self.similarity_after(&left, &right, 1)?;
+        self.distance.update(&left, &right, 1)?;
+        self.similarity = similarity;
+        self.count(left, 0, 1);
+        self.count(right, 1, 1);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes one `left` from the left list and one `right` from the right one, which need
+    /// not have been inserted together. Returns `false` and changes nothing if either is not
+    /// in its list, and `Overflow` if a metric would no longer fit `T`.
+    pub fn remove(&mut self, left: &T, right: &T) -> Result<bool, Overflow> {
+        if self.occurrences(left, 0) == 0 || self.occurrences(right, 1) == 0 {
+            return Ok(false);
+        }
+        let similarity = self.similarity_after(left, right, -1)?;
+        self.distance.update(left, right, -1)?;
+        self.similarity = similarity;
+        self.count(left.clone(), 0, -1);
+        self.count(right.clone(), 1, -1);
+        self.len -= 1;
+        Ok(true)
+    }
+
+    /// The total distance, as given by `calculate_distances`.
+    pub fn distance(&self) -> &T {
+        &self.distance.total
+    }
+
+    /// The similarity score, as given by `calculate_similarity`.
+    pub fn similarity(&self) -> &T {
+        &self.similarity
+    }
+
+    /// The number of pairs.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn occurrences(&self, id: &T, side: usize) -> i64 {
+        self.counts.get(id).map_or(0, |counts| counts[side])
+    }
+
+    /// The similarity after adding `delta` of both `left` and `right`. Each ID on one side
+    /// adds itself times its occurrences on the other, counting `left` if it equals `right`.
+    fn similarity_after(&self, left: &T, right: &T, delta: i64) -> Result<T, Overflow> {
+        let same = i64::from(left == right);
+        let terms = [
+            (left, self.occurrences(left, 1)),
+            (right, self.occurrences(right, 0) + delta * same),
+        ];
+        terms
+            .into_iter()
+            .try_fold(self.similarity.clone(), |total, (id, other)| {
+                let term = T::from_i64(other).and_then(|count| id.checked_mul(&count));
+                let total = if delta > 0 {
+                    term.and_then(|term| total.checked_add(&term))
+                } else {
+                    term.and_then(|term| total.checked_sub(&term))
+                };
+                total.ok_or(Overflow)
+            })
+    }
+
+    /// Changes how often `id` occurs on `side`, 0 for left and 1 for right, by `delta`.
+    fn count(&mut self, id: T, side: usize, delta: i64) {
+        let counts = self.counts.entry(id.clone()).or_default();
+        counts[side] += delta;
+        if *counts == [0, 0] {
+            self.counts.remove(&id);
+        }
+    }
+}
+
+impl<T: Id> Default for Incremental<T> {
+    fn default() -> Self {
+        Incremental::new()
+    }
+}
+
+/// The sum of `|D(x)|`, with `D` kept as segments that each start at an ID and end at the
+/// next one. `D` is 0 before the first ID and from the last one on.
+#[derive(Debug, Clone)]
+struct Distance<T> {
+    /// Never empty, the first segment of each block starts after the last of the one before.
+    blocks: Vec<Block<T>>,
+    total: T,
+    segments: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Block<T> {
+    segments: Vec<Segment<T>>,
+    /// Added to the `level` of every segment.
+    offset: i64,
+    /// The distinct levels of the segments, in order, each with the total width of the
+    /// segments at that level or below.
+    by_level: Vec<(i64, T)>,
+    /// Whether `by_level` is out of date, so that it is rebuilt once per update however
+    /// often the block changes.
+    stale: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Segment<T> {
+    start: T,
+    /// Left IDs minus right IDs equal to `start`. Segments where this is 0 are merged into
+    /// the one before, `D` does not change there.
+    net: i64,
+    /// `D` on the segment, less the block's offset.
+    level: i64,
+    /// Up to the next segment, `None` for the last one, where `D` is 0.
+    width: Option<T>,
+}
+
+impl<T: Id> Distance<T> {
+    fn new() -> Self {
+        Distance {
+            blocks: Vec::new(),
+            total: T::zero(),
+            segments: 0,
+        }
+    }
+
+    /// Adds `delta` left IDs `left` and right IDs `right`. Changes nothing if the distance,
+    /// or the difference between two IDs, would not fit `T`.
+    fn update(&mut self, left: &T, right: &T, delta: i64) -> Result<(), Overflow> {
+        if left == right {
+            return Ok(());
+        }
+        self.check_span(left)?;
+        self.check_span(right)?;
+
+        let (from, to, step) = if left < right {
+            (left, right, delta)
+        } else {
+            (right, left, -delta)
+        };
+        self.split_at(from);
+        self.split_at(to);
+        let change = self.add(from, to, step);
+        let result = match self.total.checked_add(&change) {
+            Some(total) => {
+                self.total = total;
+                self.segment(left).net += delta;
+                self.segment(right).net -= delta;
+                Ok(())
+            }
+            None => {
+                self.add(from, to, -step);
+                Err(Overflow)
+            }
+        };
+        self.merge_unused(from);
+        self.merge_unused(to);
+        self.rebalance();
+        result
+    }
+
+    /// Makes sure that the distance between any two IDs fits `T` once `id` is added, which
+    /// bounds every width and every sum of them below.
+    fn check_span(&self, id: &T) -> Result<(), Overflow> {
+        let (Some(first), Some(last)) = (self.blocks.first(), self.blocks.last()) else {
+            return Ok(());
+        };
+        let first = &first.segments[0].start;
+        let last = &last.segments.last().unwrap().start;
+        last.max(id)
+            .checked_sub(first.min(id))
+            .map(|_| ())
+            .ok_or(Overflow)
+    }
+
+    /// The block and index of the last segment starting at or before `id`.
+    fn locate(&self, id: &T) -> Option<(usize, usize)> {
+        let b = self
+            .blocks
+            .partition_point(|block| block.segments[0].start <= *id);
+        let i = self
+            .blocks
+            .get(b.checked_sub(1)?)?
+            .segments
+            .partition_point(|s| s.start <= *id);
+        Some((b - 1, i - 1))
+    }
+
+    fn segment(&mut self, id: &T) -> &mut Segment<T> {
+        let (b, i) = self.locate(id).unwrap();
+        &mut self.blocks[b].segments[i]
+    }
+
+    /// Makes sure that a segment starts at `id`.
+    fn split_at(&mut self, id: &T) {
+        match self.locate(id) {
+            Some((b, i)) if self.blocks[b].segments[i].start == *id => return,
+            Some((b, i)) => {
+                let block = &mut self.blocks[b];
+                let before = &mut block.segments[i];
+                let width = id.checked_sub(&before.start).unwrap();
+                let segment = Segment {
+                    start: id.clone(),
+                    net: 0,
+                    level: before.level,
+                    width: before.width.take().map(|w| w.checked_sub(&width).unwrap()),
+                };
+                before.width = Some(width);
+                block.segments.insert(i + 1, segment);
+                block.stale = true;
+            }
+            None => {
+                let width = self
+                    .blocks
+                    .first()
+                    .map(|block| block.segments[0].start.checked_sub(id).unwrap());
+                if self.blocks.is_empty() {
+                    self.blocks.push(Block::new(Vec::new()));
+                }
+                let block = &mut self.blocks[0];
+                let segment = Segment {
+                    start: id.clone(),
+                    net: 0,
+                    level: -block.offset,
+                    width,
+                };
+                block.segments.insert(0, segment);
+                block.stale = true;
+            }
+        }
+        self.segments += 1;
+    }
+
+    /// Removes the segment starting at `id` if no ID starts it any more, its range goes to
+    /// the segment before.
+    fn merge_unused(&mut self, id: &T) {
+        let (b, i) = self.locate(id).unwrap();
+        if self.blocks[b].segments[i].net != 0 {
+            return;
+        }
+        let removed = self.blocks[b].segments.remove(i);
+        self.segments -= 1;
+        let before = match (b, i) {
+            (_, 1..) => Some((b, i - 1)),
+            (1.., 0) => Some((b - 1, self.blocks[b - 1].segments.len() - 1)),
+            (0, 0) => None,
+        };
+        if let Some((pb, pi)) = before {
+            let before = &mut self.blocks[pb].segments[pi];
+            before.width = match (before.width.take(), removed.width) {
+                (Some(a), Some(b)) => Some(a.checked_add(&b).unwrap()),
+                _ => None,
+            };
+            self.blocks[pb].stale = true;
+        }
+        if self.blocks[b].segments.is_empty() {
+            self.blocks.remove(b);
+        } else {
+            self.blocks[b].stale = true;
+        }
+    }
+
+    /// Adds `delta`, 1 or -1, to `D` from the segment starting at `from` up to the one
+    /// starting at `to`, and returns how much the sum of `|D|` changed.
+    fn add(&mut self, from: &T, to: &T, delta: i64) -> T {
+        let (first, start) = self.locate(from).unwrap();
+        let (last, end) = self.locate(to).unwrap();
+        let mut change = T::zero();
+        for b in first..=last {
+            budget::step();
+            let block = &mut self.blocks[b];
+            let start = if b == first { start } else { 0 };
+            let end = if b == last { end } else { block.segments.len() };
+            let block_change = if start == 0 && end == block.segments.len() {
+                block.shift(delta)
+            } else {
+                block.add(start..end, delta)
+            };
+            // Bounded by the width of the blocks so far, which fits.
+            change = change.checked_add(&block_change).unwrap();
+        }
+        change
+    }
+
+    /// Keeps the blocks at about `√n` segments, splitting one that grew too long and merging
+    /// one pair of neighbours that became too short, and brings the blocks that changed up to
+    /// date. At most four blocks change in an update, so this takes `O(√n log n)` steps.
+    fn rebalance(&mut self) {
+        let target = (self.segments as f64).sqrt() as usize + 16;
+        let mut b = 0;
+        let mut merged = false;
+        while b < self.blocks.len() {
+            budget::step();
+            if self.blocks[b].segments.len() > 2 * target {
+                let block = &mut self.blocks[b];
+                let half = block.segments.split_off(block.segments.len() / 2);
+                let mut second = Block::new(half);
+                second.offset = block.offset;
+                block.stale = true;
+                self.blocks.insert(b + 1, second);
+            } else if !merged
+                && b + 1 < self.blocks.len()
+                && self.blocks[b].segments.len() + self.blocks[b + 1].segments.len() <= target
+            {
+                let next = self.blocks.remove(b + 1);
+                let block = &mut self.blocks[b];
+                block
+                    .segments
+                    .extend(next.segments.into_iter().map(|mut segment| {
+                        segment.level += next.offset - block.offset;
+                        segment
+                    }));
+                block.stale = true;
+                merged = true;
+            }
+            if self.blocks[b].stale {
+                self.blocks[b].rebuild();
+            }
+            b += 1;
+        }
+    }
+}
+
+impl<T: Id> Block<T> {
+    fn new(segments: Vec<Segment<T>>) -> Self {
+        Block {
+            segments,
+            offset: 0,
+            by_level: Vec::new(),
+            stale: true,
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let mut widths: Vec<(i64, &T)> = self
+            .segments
+            .iter()
+            .inspect(|_| budget::step())
+            .filter_map(|s| Some((s.level, s.width.as_ref()?)))
+            .collect();
+        widths.sort_unstable_by_key(|&(level, _)| level);
+        self.stale = false;
+        self.by_level.clear();
+        let mut total = T::zero();
+        for (level, width) in widths {
+            total = total.checked_add(width).unwrap();
+            match self.by_level.last_mut() {
+                Some((last, sum)) if *last == level => *sum = total.clone(),
+                _ => self.by_level.push((level, total.clone())),
+            }
+        }
+    }
+
+    /// The total width of the segments with `D` below `d`.
+    fn width_below(&self, d: i64) -> T {
+        let i = self
+            .by_level
+            .partition_point(|(level, _)| level + self.offset < d);
+        i.checked_sub(1)
+            .map_or_else(T::zero, |i| self.by_level[i].1.clone())
+    }
+
+    /// Adds `delta` to the whole block.
+    fn shift(&mut self, delta: i64) -> T {
+        if self.stale {
+            self.rebuild();
+        }
+        let total = self.width_below(i64::MAX);
+        // Adding 1 grows `|D|` where it is at least 0, subtracting 1 where it is at most 0.
+        let (grows, shrinks) = if delta > 0 {
+            let negative = self.width_below(0);
+            (total.checked_sub(&negative).unwrap(), negative)
+        } else {
+            let not_positive = self.width_below(1);
+            let positive = total.checked_sub(&not_positive).unwrap();
+            (not_positive, positive)
+        };
+        self.offset += delta;
+        grows.checked_sub(&shrinks).unwrap()
+    }
+
+    /// Adds `delta` to the segments in `range`.
+    fn add(&mut self, range: Range<usize>, delta: i64) -> T {
+        let mut change = T::zero();
+        for segment in &mut self.segments[range] {
+            budget::step();
+            let d = segment.level + self.offset;
+            let width = segment.width.as_ref().unwrap();
+            change = if (delta > 0 && d >= 0) || (delta < 0 && d <= 0) {
+                change.checked_add(width)
+            } else {
+                change.checked_sub(width)
+            }
+            .unwrap();
+            segment.level += delta;
+        }
+        self.stale = true;
+        change
+    }
+}
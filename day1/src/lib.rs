@@ -0,0 +1,134 @@
+pub mod columns;
+pub mod external;
+pub mod generate;
+mod id;
+pub mod incremental;
+
+use std::{collections::HashMap, str::FromStr};
+
+use common::{Answer, ParseError, Solution};
+use num_bigint::BigInt;
+use rand::rngs::StdRng;
+
+pub use id::{Id, Overflow};
+
+const LIST_LEN: usize = 1000;
+
+pub struct Day1;
+
+/// The two lists, in `i64` unless an ID does not fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lists {
+    Narrow(Vec<i64>, Vec<i64>),
+    Wide(Vec<BigInt>, Vec<BigInt>),
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        match get_input(input) {
+            Ok((left, right)) => Ok(Lists::Narrow(left, right)),
+            Err(narrow) => match get_input(input) {
+                Ok((left, right)) => Ok(Lists::Wide(left, right)),
+                Err(_) => Err(narrow),
+            },
+        }
+    }
+
+    fn part1(lists: &Self::Input) -> Answer {
+        lists.metric(calculate_distances, calculate_distances)
+    }
+
+    fn part2(lists: &Self::Input) -> Answer {
+        lists.metric(calculate_similarity, calculate_similarity)
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+type Metric<T> = fn(Vec<T>, Vec<T>) -> Result<T, Overflow>;
+
+impl Lists {
+    /// The metric computed in `i64`, or again in `BigInt` if that overflowed.
+    fn metric(&self, narrow: Metric<i64>, wide: Metric<BigInt>) -> Answer {
+        let (left, right) = match self {
+            Lists::Narrow(left, right) => match narrow(left.clone(), right.clone()) {
+                Ok(answer) => return answer.into(),
+                Err(Overflow) => (widen(left), widen(right)),
+            },
+            Lists::Wide(left, right) => (left.clone(), right.clone()),
+        };
+        let answer = wide(left, right).expect("a BigInt does not overflow");
+        match i128::try_from(&answer) {
+            Ok(answer) => answer.into(),
+            Err(_) => answer.to_string().into(),
+        }
+    }
+}
+
+/// `ids` in `BigInt`, for when a metric of them does not fit their type.
+pub fn widen<T: Into<BigInt> + Clone>(ids: &[T]) -> Vec<BigInt> {
+    ids.iter().cloned().map(Into::into).collect()
+}
+
+pub fn get_input<T: FromStr>(input: &str) -> Result<(Vec<T>, Vec<T>), ParseError> {
+    let mut left: Vec<T> = Vec::with_capacity(LIST_LEN);
+    let mut right: Vec<T> = Vec::with_capacity(LIST_LEN);
+
+    for line in input.lines() {
+        let (l, r) = parse_pair(input, line)?;
+        left.push(l);
+        right.push(r);
+    }
+
+    Ok((left, right))
+}
+
+/// The two IDs on `line`, separated by any whitespace.
+fn parse_pair<T: FromStr>(input: &str, line: &str) -> Result<(T, T), ParseError> {
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [l, r] => Ok((parsing::value(input, l)?, parsing::value(input, r)?)),
+        _ => Err(ParseError::at(
+            input,
+            line,
+            "expected two IDs separated by whitespace",
+        )),
+    }
+}
+
+pub fn calculate_distances<T: Id>(mut left: Vec<T>, mut right: Vec<T>) -> Result<T, Overflow> {
+    left.sort();
+    right.sort();
+
+    left.iter()
+        .zip(right.iter())
+        .try_fold(T::zero(), |total, (l, r)| {
+            let distance = if l >= r {
+                l.checked_sub(r)
+            } else {
+                r.checked_sub(l)
+            };
+            distance
+                .and_then(|distance| total.checked_add(&distance))
+                .ok_or(Overflow)
+        })
+}
+
+pub fn calculate_similarity<T: Id>(left: Vec<T>, right: Vec<T>) -> Result<T, Overflow> {
+    let occurrences = right.iter().fold(HashMap::new(), |mut map, v| {
+        map.entry(v).and_modify(|item| *item += 1).or_insert(1);
+        map
+    });
+
+    left.iter().try_fold(T::zero(), |total, i| {
+        let count = occurrences.get(i).copied().unwrap_or(0);
+        T::from_usize(count)
+            .and_then(|count| i.checked_mul(&count))
+            .and_then(|score| total.checked_add(&score))
+            .ok_or(Overflow)
+    })
+}
@@ -0,0 +1,57 @@
+//! The metrics for lists with more than two columns.
+
+use day1::{
+    columns::{self, Matrix},
+    Overflow,
+};
+use num_bigint::BigInt;
+
+#[test]
+fn every_pair_of_columns_is_compared() {
+    let table = columns::parse_table::<i64>("a b c\n3 4 1\n4 3 1\n2 5 3\n", true).unwrap();
+    assert_eq!(table.columns, [vec![3, 4, 2], vec![4, 3, 5], vec![1, 1, 3]]);
+    assert_eq!(
+        table.distances().unwrap(),
+        Matrix {
+            names: vec!["a".into(), "b".into(), "c".into()],
+            values: vec![vec![0, 3, 4], vec![3, 0, 7], vec![4, 7, 0]],
+        }
+    );
+    assert_eq!(
+        table.similarities().unwrap().values,
+        [vec![9, 7, 3], vec![7, 12, 3], vec![3, 3, 7]]
+    );
+}
+
+#[test]
+fn two_columns_give_the_puzzle_answers() {
+    let example = include_str!("../input_test.txt");
+    let table = columns::parse_table::<i64>(example, false).unwrap();
+    assert_eq!(table.names(), ["1", "2"]);
+    assert_eq!(table.distances().unwrap().values[0][1], 11);
+    assert_eq!(table.similarities().unwrap().values[0][1], 31);
+    assert_eq!(
+        table.distances().unwrap().to_string(),
+        "    1   2\n1   0  11\n2  11   0\n"
+    );
+}
+
+#[test]
+fn rows_must_have_as_many_columns_as_the_first() {
+    let error = columns::parse_table::<i64>("1 2 3\n4 5\n", false).unwrap_err();
+    assert_eq!(error.message(), "expected 3 columns, found 2");
+    assert_eq!(error.line(), Some(2));
+
+    let error = columns::parse_table::<i64>("left right\n1 2 3\n", true).unwrap_err();
+    assert_eq!(error.message(), "expected 2 columns, found 3");
+}
+
+#[test]
+fn metrics_that_do_not_fit_are_overflows() {
+    let table = columns::parse_table::<i32>("2147483647 -1\n", false).unwrap();
+    assert_eq!(table.distances(), Err(Overflow));
+    assert_eq!(
+        table.widen().distances().unwrap().values[0][1],
+        BigInt::from(1u64 << 31)
+    );
+}
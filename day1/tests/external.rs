@@ -0,0 +1,61 @@
+//! The external-memory mode against the in-memory solution.
+
+use day1::external::{self, Totals};
+use num_bigint::BigInt;
+use rand::{rngs::StdRng, SeedableRng};
+
+fn in_memory(input: &str) -> Totals<i64> {
+    let (left, right) = day1::get_input::<i64>(input).unwrap();
+    Totals {
+        distance: day1::calculate_distances(left.clone(), right.clone()).unwrap(),
+        similarity: day1::calculate_similarity(left, right).unwrap(),
+    }
+}
+
+#[test]
+fn matches_the_in_memory_answers() {
+    let mut rng = StdRng::seed_from_u64(2024);
+    for size in [0, 1, 5, 200] {
+        let input = day1::generate::generate(&mut rng, size);
+        // A run length of 1 spills more runs than are merged at once.
+        for run_len in [1, 3, 64, 1000] {
+            assert_eq!(
+                external::reconcile(input.as_bytes(), run_len).unwrap(),
+                in_memory(&input),
+                "{size} pairs in runs of {run_len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn reports_the_line_that_does_not_parse() {
+    let error = external::reconcile::<i64>("3   4\n4   x\n".as_bytes(), 1).unwrap_err();
+    assert_eq!(error.to_string(), "line 2: cannot parse x");
+}
+
+#[test]
+fn ids_and_totals_that_do_not_fit_are_overflows() {
+    let input = "9223372036854775807   -1\n";
+    assert!(matches!(
+        external::reconcile::<i64>(input.as_bytes(), 1),
+        Err(external::Error::Overflow(_))
+    ));
+    assert!(matches!(
+        external::reconcile::<i32>(input.as_bytes(), 1),
+        Err(external::Error::Overflow(_))
+    ));
+    assert_eq!(
+        external::reconcile::<BigInt>(input.as_bytes(), 1).unwrap(),
+        Totals {
+            distance: BigInt::from(i64::MAX) + 1,
+            similarity: BigInt::from(0),
+        }
+    );
+}
+
+#[test]
+fn ids_may_be_separated_by_any_whitespace() {
+    let totals = external::reconcile::<i64>("3\t4\n4 3\n2  \t 5\n".as_bytes(), 2).unwrap();
+    assert_eq!(totals, in_memory("3   4\n4   3\n2   5\n"));
+}
@@ -0,0 +1,142 @@
+//! The incremental metrics against computing them from scratch.
+
+use common::budget::{self, Budget};
+use day1::{incremental::Incremental, Overflow};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Both metrics the way the puzzle defines them, in `i64` so that extreme IDs do not
+/// overflow.
+fn from_scratch(left: &[i32], right: &[i32]) -> (i64, i64) {
+    let mut left: Vec<i64> = left.iter().map(|&id| id.into()).collect();
+    let mut right: Vec<i64> = right.iter().map(|&id| id.into()).collect();
+    left.sort();
+    right.sort();
+    let distance = left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum();
+    let similarity = left
+        .iter()
+        .map(|l| l * right.iter().filter(|r| *r == l).count() as i64)
+        .sum();
+    (distance, similarity)
+}
+
+fn metrics<T: day1::Id>(incremental: &Incremental<T>) -> (T, T) {
+    (
+        incremental.distance().clone(),
+        incremental.similarity().clone(),
+    )
+}
+
+#[test]
+fn matches_the_puzzle_example() {
+    let (left, right) = day1::get_input::<i32>(include_str!("../input_test.txt")).unwrap();
+    let incremental = Incremental::from_pairs(left.into_iter().zip(right)).unwrap();
+    assert_eq!(incremental.len(), 6);
+    assert_eq!(metrics(&incremental), (11, 31));
+}
+
+#[test]
+fn stays_up_to_date_through_insertions_and_removals() {
+    let mut rng = StdRng::seed_from_u64(2024);
+    let id = |rng: &mut StdRng| match rng.gen_range(0..20) {
+        0 => i32::MIN,
+        1 => i32::MAX,
+        _ => rng.gen_range(-50..50),
+    };
+    let mut incremental = Incremental::new();
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    for step in 0..2000 {
+        if !left.is_empty() && rng.gen_bool(0.4) {
+            // The IDs removed together need not have been inserted together.
+            let l: i32 = left.swap_remove(rng.gen_range(0..left.len()));
+            let r: i32 = right.swap_remove(rng.gen_range(0..right.len()));
+            assert_eq!(
+                incremental.remove(&l.into(), &r.into()),
+                Ok(true),
+                "step {step}"
+            );
+        } else {
+            let (l, r) = (id(&mut rng), id(&mut rng));
+            incremental.insert(l.into(), r.into()).unwrap();
+            left.push(l);
+            right.push(r);
+        }
+        assert_eq!(
+            metrics::<i64>(&incremental),
+            from_scratch(&left, &right),
+            "step {step}"
+        );
+        assert_eq!(incremental.len(), left.len());
+    }
+}
+
+#[test]
+fn removing_what_is_not_there_changes_nothing() {
+    let mut incremental = Incremental::from_pairs([(3, 4), (4, 3)]).unwrap();
+    assert_eq!(incremental.remove(&5, &4), Ok(false));
+    assert_eq!(incremental.remove(&3, &5), Ok(false));
+    assert_eq!(metrics(&incremental), (0, 7));
+    assert_eq!(incremental.remove(&4, &4), Ok(true));
+    assert_eq!(incremental.remove(&3, &3), Ok(true));
+    assert!(incremental.is_empty());
+    assert_eq!(metrics(&incremental), (0, 0));
+}
+
+#[test]
+fn overflow_is_reported_and_changes_nothing() {
+    let mut incremental = Incremental::from_pairs([(i32::MAX, 1), (2, i32::MAX)]).unwrap();
+    let before = metrics(&incremental);
+    // The distance between the IDs does not fit.
+    assert_eq!(incremental.insert(i32::MIN, 0), Err(Overflow));
+    // Neither does the similarity once `i32::MAX` occurs twice on either side.
+    assert_eq!(incremental.insert(i32::MAX, i32::MAX), Err(Overflow));
+    assert_eq!(metrics(&incremental), before);
+    assert_eq!(incremental.len(), 2);
+    assert_eq!(incremental.remove(&2, &1), Ok(true));
+    assert_eq!(metrics(&incremental), (0, i32::MAX));
+}
+
+/// The fewest steps that inserting and then removing a pair takes for `n` pairs, when `D`
+/// alternates between 1 and -1 and the pair spans all of them, flipping the sign of `D`
+/// everywhere in between.
+fn steps_to_flip(n: i64) -> u64 {
+    let mut incremental = Incremental::new();
+    for i in 0..n {
+        incremental.insert(4 * i, 4 * i + 1).unwrap();
+        incremental.insert(4 * i + 3, 4 * i + 2).unwrap();
+    }
+    let expected = metrics(&incremental);
+    let flips_within = |max_steps: u64| {
+        let budget = Budget {
+            max_steps: Some(max_steps),
+            timeout: None,
+        };
+        let mut flipped = incremental.clone();
+        let flip = budget::limit(budget, || {
+            flipped.insert(-1, 4 * n).unwrap();
+            assert_eq!(flipped.remove(&-1, &(4 * n)), Ok(true));
+        });
+        flip.is_ok() && metrics(&flipped) == expected
+    };
+
+    let (mut fewest, mut most) = (0, 1 << 20);
+    while most - fewest > 1 {
+        let middle = (fewest + most) / 2;
+        if flips_within(middle) {
+            most = middle;
+        } else {
+            fewest = middle;
+        }
+    }
+    most
+}
+
+#[test]
+fn work_per_update_grows_with_the_square_root_of_the_length() {
+    // 25 times as many pairs, about 5 times the steps, give or take the logarithm.
+    let small = steps_to_flip(1_000);
+    let large = steps_to_flip(25_000);
+    assert!(
+        large <= 10 * small,
+        "{small} steps for 1000 pairs, {large} for 25000"
+    );
+}
@@ -0,0 +1,54 @@
+//! The metrics in integer types of every width, and what happens when they do not fit.
+
+use common::{Answer, Solution};
+use day1::{calculate_distances, calculate_similarity, Day1, Lists, Overflow};
+use num_bigint::BigInt;
+
+#[test]
+fn metrics_report_overflow() {
+    let (left, right) = (vec![i32::MIN, 0], vec![i32::MAX, 0]);
+    assert_eq!(
+        calculate_distances(left.clone(), right.clone()),
+        Err(Overflow)
+    );
+    assert_eq!(
+        calculate_similarity(vec![i32::MAX; 2], vec![i32::MAX]),
+        Err(Overflow)
+    );
+
+    let widen = |ids: &[i32]| ids.iter().map(|&id| i64::from(id)).collect::<Vec<_>>();
+    assert_eq!(
+        calculate_distances(widen(&left), widen(&right)),
+        Ok(i64::from(u32::MAX))
+    );
+    let huge = vec![i128::MAX / 2, i128::MAX / 2];
+    assert_eq!(
+        calculate_similarity(huge.clone(), huge.clone()),
+        Err(Overflow)
+    );
+    let huge: Vec<BigInt> = huge.into_iter().map(BigInt::from).collect();
+    assert_eq!(
+        calculate_similarity(huge.clone(), huge),
+        Ok(BigInt::from(i128::MAX / 2) * 4)
+    );
+}
+
+#[test]
+fn answers_widen_instead_of_overflowing() {
+    let lists = Day1::parse("-9223372036854775808   9223372036854775807\n").unwrap();
+    assert_eq!(Day1::part1(&lists), Answer::from(u64::MAX));
+    let lists = Day1::parse(&"9223372036854775807   9223372036854775807\n".repeat(40)).unwrap();
+    assert_eq!(
+        Day1::part2(&lists),
+        Answer::from(i128::from(i64::MAX) * 1600)
+    );
+}
+
+#[test]
+fn ids_beyond_i64_are_parsed_wide() {
+    let lists = Day1::parse("-9223372036854775809   9223372036854775808\n").unwrap();
+    assert!(matches!(lists, Lists::Wide(..)));
+    assert_eq!(Day1::part1(&lists), Answer::from(i128::from(u64::MAX) + 2));
+    assert_eq!(Day1::part2(&lists), Answer::from(0));
+    assert!(Day1::parse("1   x\n").is_err());
+}
@@ -0,0 +1,13 @@
+//! Reading the two lists.
+
+use common::Solution;
+use day1::{Day1, Lists};
+
+#[test]
+fn ids_may_be_separated_by_any_whitespace() {
+    let lists = Day1::parse("3\t4\n4 3\n").unwrap();
+    assert_eq!(lists, Lists::Narrow(vec![3, 4], vec![4, 3]));
+    let error = Day1::parse("3   4\n4   3   5\n").unwrap_err();
+    assert_eq!(error.message(), "expected two IDs separated by whitespace");
+    assert_eq!(error.line(), Some(2));
+}
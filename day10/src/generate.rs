@@ -0,0 +1,42 @@
+//! Random topographic maps.
+
+use grid::Vec2;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// A `size` by `size` map of random heights with about `size / 2` hiking trails walked into
+/// it, each climbing from a 0 as far as it can towards a 9.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1) as i64;
+    let mut map: Vec<Vec<u32>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range(0..=9)).collect())
+        .collect();
+
+    for _ in 0..=size / 2 {
+        let mut position = Vec2::new(rng.gen_range(0..size), rng.gen_range(0..size));
+        map[position.y as usize][position.x as usize] = 0;
+        let mut trail = vec![position];
+        for height in 1..=9 {
+            let next = Vec2::CARDINAL
+                .map(|direction| position + direction)
+                .into_iter()
+                .filter(|p| (0..size).contains(&p.x) && (0..size).contains(&p.y))
+                .filter(|p| !trail.contains(p))
+                .collect::<Vec<_>>();
+            let Some(&next) = next.choose(rng) else {
+                break;
+            };
+            map[next.y as usize][next.x as usize] = height;
+            trail.push(next);
+            position = next;
+        }
+    }
+
+    map.iter()
+        .map(|row| {
+            row.iter()
+                .map(|&h| char::from_digit(h, 10).unwrap())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,113 @@
+pub mod generate;
+
+use std::collections::HashSet;
+
+use common::{budget, Answer, ParseError, Solution};
+use grid::{Grid, Vec2};
+use rand::rngs::StdRng;
+
+pub type Map = Grid<usize>;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Map, ParseError> {
+    parsing::digit_grid(input)
+}
+
+pub type Location = Vec2;
+
+pub fn traverse_peaks(level: usize, location: &Location, map: &Map, peaks: &mut HashSet<Location>) {
+    budget::step();
+    let Some(height) = map.get(*location) else {
+        return;
+    };
+
+    let new_level = height + 1;
+    if new_level != level + 1 {
+        return;
+    }
+
+    if level == 9 {
+        peaks.insert(*location);
+        return;
+    }
+
+    for direction in Vec2::CARDINAL {
+        let next_location = *location + direction;
+        traverse_peaks(new_level, &next_location, map, peaks);
+    }
+}
+
+pub fn solve_part1(input: &Map) -> usize {
+    let trailheads = input.find_all(&0).collect::<Vec<Location>>();
+
+    trailheads
+        .iter()
+        .map(|trailhead| {
+            let mut peaks: HashSet<Location> = HashSet::new();
+            traverse_peaks(0, trailhead, input, &mut peaks);
+            let score = peaks.len();
+            log::debug!("{trailhead}: {score} <- {peaks:?}");
+            score
+        })
+        // .inspect(|score| println!("{score}"))
+        .sum()
+}
+
+pub fn traverse_peaks_part2(level: usize, location: &Location, map: &Map, peaks: &mut usize) {
+    budget::step();
+    let Some(height) = map.get(*location) else {
+        return;
+    };
+
+    let new_level = height + 1;
+    if new_level != level + 1 {
+        return;
+    }
+
+    if level == 9 {
+        *peaks += 1;
+        return;
+    }
+
+    for direction in Vec2::CARDINAL {
+        let next_location = *location + direction;
+        traverse_peaks_part2(new_level, &next_location, map, peaks);
+    }
+}
+
+pub fn solve_part2(input: &Map) -> usize {
+    let trailheads = input.find_all(&0).collect::<Vec<Location>>();
+
+    trailheads
+        .iter()
+        .map(|trailhead| {
+            let mut peaks = 0;
+            traverse_peaks_part2(0, trailhead, input, &mut peaks);
+            log::debug!("{trailhead}: {peaks}");
+            peaks
+        })
+        // .inspect(|score| println!("{score}"))
+        .sum()
+}
@@ -0,0 +1,14 @@
+//! Random stone lists.
+
+use rand::{rngs::StdRng, Rng};
+
+/// Up to `size` stones with up to 7 digits, like the real input.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let stones = (0..rng.gen_range(1..=size.max(1)))
+        .map(|_| {
+            let digits = rng.gen_range(1..=7);
+            rng.gen_range(0..10usize.pow(digits)).to_string()
+        })
+        .collect::<Vec<_>>();
+    stones.join(" ") + "\n"
+}
@@ -0,0 +1,121 @@
+pub mod generate;
+
+use std::collections::HashMap;
+
+use common::{Answer, ParseError, Part, Solution};
+use rand::rngs::StdRng;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    // 75 blinks of the explicit vector do not fit in memory, so part 2 has no reference.
+    fn reference(part: Part) -> Option<fn(&Self::Input) -> Answer> {
+        match part {
+            Part::One => Some(|stones| calculate_blinks(stones, 25).into()),
+            Part::Two => None,
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::words(input, input)
+}
+
+pub enum StoneResult {
+    SingleStone(usize),
+    DoubleStone(usize, usize),
+}
+
+/// What a stone engraved with `v` turns into after one blink.
+pub fn process_single_stone(v: usize) -> StoneResult {
+    if v == 0 {
+        return StoneResult::SingleStone(1);
+    }
+    let v_str = v.to_string();
+    if v_str.len().is_multiple_of(2) {
+        let (left, right) = (&v_str[0..v_str.len() / 2], &v_str[v_str.len() / 2..]);
+        let (left, right) = (left.parse().unwrap(), right.parse().unwrap());
+        return StoneResult::DoubleStone(left, right);
+    }
+
+    StoneResult::SingleStone(v * 2024)
+}
+
+pub fn calculate_blinks(stones: &[usize], blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
+    for i in 0..blinks {
+        stones = stones
+            .iter()
+            .map(|stone| process_single_stone(*stone))
+            .fold(Vec::new(), |mut acc, stone| match stone {
+                StoneResult::SingleStone(v) => {
+                    acc.push(v);
+                    acc
+                }
+                StoneResult::DoubleStone(v1, v2) => {
+                    acc.push(v1);
+                    acc.push(v2);
+                    acc
+                }
+            });
+        log::trace!("{i}: {}", stones.len());
+    }
+    stones.len()
+}
+
+pub fn calculate_blinks_fast(stones: &[usize], blinks: usize) -> usize {
+    let mut stones = stones
+        .iter()
+        .fold(HashMap::new(), |mut acc: HashMap<usize, usize>, &next| {
+            acc.entry(next).and_modify(|c| *c += 1).or_insert(1);
+            acc
+        });
+
+    log::debug!("0: {} stones -> {:?}", stones.len(), stones);
+    for _i in 0..blinks {
+        stones = stones.iter().fold(
+            HashMap::new(),
+            |mut acc: HashMap<usize, usize>, (stone, count)| {
+                match process_single_stone(*stone) {
+                    StoneResult::SingleStone(v) => {
+                        acc.entry(v).and_modify(|c| *c += *count).or_insert(*count);
+                    }
+                    StoneResult::DoubleStone(v1, v2) => {
+                        acc.entry(v1).and_modify(|c| *c += *count).or_insert(*count);
+                        acc.entry(v2).and_modify(|c| *c += *count).or_insert(*count);
+                    }
+                };
+                acc
+            },
+        );
+        // println!("{}: {} stones -> {:?}", i + 1, stones.len(), stones);
+    }
+    stones.iter().fold(0, |acc, (_, count)| acc + count)
+}
+
+pub fn solve_part1(stones: &[usize]) -> usize {
+    calculate_blinks_fast(stones, 25)
+}
+
+pub fn solve_part2(stones: &[usize]) -> usize {
+    calculate_blinks_fast(stones, 75)
+}
@@ -0,0 +1,27 @@
+//! Random garden plots.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const PLANTS: &[char] = &['A', 'B', 'C', 'D', 'E'];
+
+/// A `size` by `size` garden of a few plant types. Plots mostly copy the plant of their left
+/// or upper neighbour, which grows regions of irregular shapes (with holes and regions of the
+/// same plant that only touch diagonally) instead of noise.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut garden = vec![vec!['.'; size]; size];
+    for y in 0..size {
+        for x in 0..size {
+            garden[y][x] = match rng.gen_range(0..10) {
+                0..=2 if x > 0 => garden[y][x - 1],
+                3..=5 if y > 0 => garden[y - 1][x],
+                _ => *PLANTS.choose(rng).unwrap(),
+            };
+        }
+    }
+
+    garden
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,122 @@
+pub mod generate;
+
+use std::collections::HashSet;
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Vec2};
+use rand::rngs::StdRng;
+
+pub type Matrix = Grid<char>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Matrix;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Matrix, ParseError> {
+    parsing::char_grid(input)
+}
+
+pub fn area(matrix: &Matrix, pos: Vec2, plant: char, seen: &mut HashSet<Vec2>) -> usize {
+    let current_plant = match matrix.get(pos) {
+        Some(c) => *c,
+        None => return 0,
+    };
+
+    if current_plant != plant {
+        return 0;
+    }
+
+    if seen.contains(&pos) {
+        return 0;
+    }
+    seen.insert(pos);
+
+    let mut sum = 1;
+    for direction in Vec2::CARDINAL {
+        sum += area(matrix, pos + direction, plant, seen)
+    }
+    sum
+}
+
+pub fn perimeter(matrix: &Matrix, pos: Vec2, plant: char, seen: &mut HashSet<Vec2>) -> usize {
+    let current_plant = match matrix.get(pos) {
+        Some(c) => *c,
+        None => return 1,
+    };
+
+    if current_plant != plant {
+        return 1;
+    }
+
+    if seen.contains(&pos) {
+        return 0;
+    }
+    seen.insert(pos);
+
+    let mut sum = 0;
+    for direction in Vec2::CARDINAL {
+        sum += perimeter(matrix, pos + direction, plant, seen)
+    }
+    sum
+}
+
+// fn solve_part1(input: &Matrix) -> usize {
+//     let mut seen_area: HashSet<Vec2> = HashSet::new();
+//     let mut seen_perimeter: HashSet<Vec2> = HashSet::new();
+//     let mut areas: HashMap<char, usize> = HashMap::new();
+//     let mut perimeters: HashMap<char, usize> = HashMap::new();
+
+//     for pos in input.positions() {
+//         let plant = input[pos];
+
+//         let area = area(input, pos, plant, &mut seen_area);
+//         *areas.entry(plant).or_insert(0) += area;
+
+//         let perimeter = perimeter(input, pos, plant, &mut seen_perimeter);
+//         *perimeters.entry(plant).or_insert(0) += perimeter;
+//     }
+//     areas
+//         .iter()
+//         .map(|(plant, area)| {
+//             let perimeter = perimeters.get(plant).unwrap();
+//             println!("\tcharacter {} has area {} and perimeter {}", plant, area, perimeter);
+//             area * perimeter
+//         })
+//         .sum()
+// }
+
+pub fn solve_part1(input: &Matrix) -> usize {
+    let mut seen_area: HashSet<Vec2> = HashSet::new();
+    let mut seen_perimeter: HashSet<Vec2> = HashSet::new();
+
+    let mut price = 0;
+    for pos in input.positions() {
+        let plant = input[pos];
+        let area = area(input, pos, plant, &mut seen_area);
+        if area == 0 {
+            continue;
+        }
+        let perimeter = perimeter(input, pos, plant, &mut seen_perimeter);
+        price += area * perimeter;
+    }
+    price
+}
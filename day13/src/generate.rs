@@ -0,0 +1,25 @@
+//! Random claw machines.
+
+use grid::Vec2;
+use rand::{rngs::StdRng, Rng};
+
+/// `size` claw machines with buttons moving 10 to 99 along each axis. About half of the
+/// prizes are reachable with at most 100 presses of each button, the others are anywhere.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let machines: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let mut button = || Vec2::new(rng.gen_range(10..100), rng.gen_range(10..100));
+            let (a, b) = (button(), button());
+            let prize = if rng.gen_bool(0.5) {
+                a * rng.gen_range(0..=100) + b * rng.gen_range(0..=100)
+            } else {
+                Vec2::new(rng.gen_range(1000..20000), rng.gen_range(1000..20000))
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.x, a.y, b.x, b.y, prize.x, prize.y
+            )
+        })
+        .collect();
+    machines.join("\n")
+}
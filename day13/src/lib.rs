@@ -0,0 +1,96 @@
+pub mod generate;
+
+use common::{Answer, ParseError, Solution};
+use grid::Vec2;
+use parsing::Pattern;
+use rand::rngs::StdRng;
+
+pub struct ProblemSet {
+    pub a: Vec2,
+    pub b: Vec2,
+    pub prize: Vec2,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<ProblemSet>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<ProblemSet>, ParseError> {
+    let pattern = Pattern::new(
+        r"(?s)Button A: X\+(\d+), Y\+(\d+).*Button B: X\+(\d+), Y\+(\d+).*Prize: X=(\d+), Y=(\d+)",
+        "a claw machine with buttons A, B and a prize",
+    );
+    parsing::paragraphs(input)
+        .map(|block| {
+            let captures = pattern.captures(input, block)?;
+            let a = Vec2::new(captures.parse(1)?, captures.parse(2)?);
+            let b = Vec2::new(captures.parse(3)?, captures.parse(4)?);
+            let prize = Vec2::new(captures.parse(5)?, captures.parse(6)?);
+            Ok(ProblemSet { a, b, prize })
+        })
+        .collect()
+}
+
+/// Solves `a * presses_a + b * presses_b = prize` for the button presses by inverting the
+/// 2x2 matrix of the buttons. The division truncates, so the caller has to check that the
+/// presses really reach the prize. `None` if the buttons move in the same direction.
+pub fn calculate_inverses(problem_set: &ProblemSet) -> Option<(i64, i64)> {
+    let (a, b, p) = (problem_set.a, problem_set.b, problem_set.prize);
+    let det = a.x * b.y - b.x * a.y;
+    if det == 0 {
+        return None;
+    }
+
+    let presses_a = p.x * b.y - p.y * b.x;
+    let presses_b = -p.x * a.y + p.y * a.x;
+    Some((presses_a / det, presses_b / det))
+}
+
+pub fn solve_part1(problem_set: &[ProblemSet]) -> i64 {
+    problem_set
+        .iter()
+        .filter_map(|p| {
+            let (presses_a, presses_b) = calculate_inverses(p)?;
+            if p.a * presses_a + p.b * presses_b != p.prize {
+                return None;
+            }
+            Some(3 * presses_a + presses_b)
+        })
+        .sum()
+}
+
+pub fn solve_part2(problem_set: &[ProblemSet]) -> i64 {
+    problem_set
+        .iter()
+        .filter_map(|p| {
+            let p = ProblemSet {
+                prize: p.prize + Vec2::new(10000000000000, 10000000000000),
+                ..*p
+            };
+            let (presses_a, presses_b) = calculate_inverses(&p)?;
+            if p.a * presses_a + p.b * presses_b != p.prize {
+                return None;
+            }
+            Some(3 * presses_a + presses_b)
+        })
+        .sum()
+}
@@ -0,0 +1,26 @@
+//! Random level reports.
+
+use rand::{rngs::StdRng, Rng};
+
+/// `size` reports of 5 to 8 levels. Most of them step steadily up or down by 1 to 3, like a
+/// safe report, and then get a bad step now and then so that both parts have work to do.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level: i64 = rng.gen_range(30..70);
+            let mut levels = vec![level];
+            for _ in 1..rng.gen_range(5..=8) {
+                let step = if rng.gen_bool(0.15) {
+                    rng.gen_range(-5..=5)
+                } else {
+                    direction * rng.gen_range(1..=3)
+                };
+                level = (level + step).clamp(1, 99);
+                levels.push(level);
+            }
+            let levels: Vec<String> = levels.iter().map(|l| l.to_string()).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
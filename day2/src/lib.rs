@@ -0,0 +1,92 @@
+pub mod generate;
+
+use common::{Answer, ParseError, Solution};
+use rand::rngs::StdRng;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| parsing::words(input, line))
+        .collect()
+}
+
+pub fn solve_part1(items: &[Vec<i64>]) -> usize {
+    // items.iter().map(|levels| {
+    //     let iter1 = levels.iter();
+    //     let iter2 = levels.iter().skip(1);
+    //     iter1.zip(iter2).map(|(l, r)| r - l)
+    // }).filter(|changes| {
+    //     changes.clone().filter(|change| {let c = change.abs(); c < 1 || c > 3}).peekable().peek().is_none()
+    // }).filter(|changes| {
+    //     changes.clone().all(|c| c > 0) || changes.clone().all(|c| c < 0)
+    // }).count()
+
+    items.iter().filter(|item| is_safe(item)).count()
+}
+
+/// All levels increasing or all decreasing, by 1 to 3 at a time.
+pub fn is_safe(levels: &[i64]) -> bool {
+    let iter = levels.iter();
+    let iter2 = levels.iter().skip(1);
+    let change = iter.zip(iter2).map(|(l, r)| r - l);
+
+    if change.clone().any(|c| {
+        let abs = c.abs();
+        !(1..=3).contains(&abs)
+    }) {
+        return false;
+    }
+    change.clone().all(|c| c > 0) || change.clone().all(|c| c < 0)
+}
+
+pub fn solve_part2(items: &[Vec<i64>]) -> usize {
+    items
+        .iter()
+        .filter(|item| {
+            (0..item.len()).any(|idx| {
+                let new: Vec<i64> = item
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| idx != *i)
+                    .map(|(_, item)| *item)
+                    .collect();
+                is_safe(&new)
+            })
+        })
+        .count()
+}
+
+// fn debug(items: &Vec<Vec<i64>>) -> Vec<Vec<i64>> {
+//     items.iter().map(|levels| {
+//         let iter1 = levels.iter();
+//         let iter2 = levels.iter().skip(1);
+//         iter1.zip(iter2).map(|(l, r)| r - l)
+//     }).filter(|changes| {
+//         changes.clone().filter(|change| {let c = change.abs(); c < 1 || c > 3}).peekable().peek().is_none()
+//     }).filter(|changes| {
+//         changes.clone().all(|c| c > 0) || changes.clone().all(|c| c < 0)
+//     }).map(|c| c.collect()).collect()
+// }
@@ -0,0 +1,33 @@
+//! Random corrupted memory.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const JUNK: &[&str] = &[
+    "%", "&", "[", "]", "!", "@", "^", "*", "+", "-", "<", ">", "{", "}", "?", ";", ":", "'", " ",
+    ",", "(", ")", "from()", "select()", "where()", "why()", "how()", "what()",
+];
+
+/// About `size` instructions: real `mul(X,Y)`s, `do()` and `don't()`, and almost-muls that
+/// must be ignored, with junk in between and a line break now and then.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut memory = String::new();
+    for _ in 0..size {
+        let (x, y) = (rng.gen_range(1..1000), rng.gen_range(1..1000));
+        let instruction = match rng.gen_range(0..10) {
+            0..=4 => format!("mul({x},{y})"),
+            5 => "do()".to_string(),
+            6 => "don't()".to_string(),
+            7 => format!("mul({x},{y}]"),
+            8 => format!("mul ( {x} , {y} )"),
+            _ => format!("mul({x}{y},{y})"),
+        };
+        memory.push_str(&instruction);
+        for _ in 0..rng.gen_range(0..3) {
+            memory.push_str(JUNK.choose(rng).unwrap());
+        }
+        if rng.gen_bool(0.05) {
+            memory.push('\n');
+        }
+    }
+    memory + "\n"
+}
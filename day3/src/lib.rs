@@ -0,0 +1,57 @@
+pub mod generate;
+
+use common::{Answer, ParseError, Solution};
+use rand::rngs::StdRng;
+use regex::Regex;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let enabled = disable(input);
+        log::debug!("enabled instructions: {enabled}");
+        part1(&enabled).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    let pattern = Regex::new("mul\\((\\d{1,3}),(\\d{1,3})\\)").unwrap();
+    pattern
+        .captures_iter(input)
+        .map(|capture| {
+            let a = capture.get(1).unwrap().as_str().parse::<i32>().unwrap();
+            let b = capture.get(2).unwrap().as_str().parse::<i32>().unwrap();
+            a * b
+        })
+        .sum()
+}
+
+pub fn disable(input: &str) -> String {
+    let pattern = Regex::new(r"(?s)don't\(\)(.*?)(do\(\))").unwrap();
+    // for capture in pattern.captures_iter(input) {
+    //     let start = capture.get(0).unwrap().start();
+    //     let end = capture.get(0).unwrap().end();
+
+    //     for i in [start..=end] {
+    //         input.
+    //     }
+    //     input.replace(from, to)
+    // }
+
+    pattern.replace_all(input, "<DISABLED>").to_string()
+}
@@ -0,0 +1,30 @@
+//! Random word searches.
+
+use grid::Vec2;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// A `size` by `size` grid of the letters of XMAS, with about `size` XMASes written into it in
+/// random directions (some of which will cross and form an X-MAS).
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut grid = vec![vec!['.'; size]; size];
+    for cell in grid.iter_mut().flatten() {
+        *cell = *['X', 'M', 'A', 'S'].choose(rng).unwrap();
+    }
+
+    for _ in 0..size {
+        let start = Vec2::new(rng.gen_range(0..size as i64), rng.gen_range(0..size as i64));
+        let direction = *Vec2::COMPASS.choose(rng).unwrap();
+        let end = start + direction * 3;
+        if (0..size as i64).contains(&end.x) && (0..size as i64).contains(&end.y) {
+            for (i, letter) in "XMAS".chars().enumerate() {
+                let position = start + direction * i as i64;
+                grid[position.y as usize][position.x as usize] = letter;
+            }
+        }
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,84 @@
+pub mod generate;
+
+use common::{Answer, ParseError, Solution};
+use grid::{Dir, Grid, Vec2};
+use rand::rngs::StdRng;
+
+pub type Matrix = Grid<char>;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Matrix;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        get_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn get_input(input: &str) -> Result<Matrix, ParseError> {
+    parsing::char_grid(input)
+}
+
+pub fn is_xmas(matrix: &Matrix, start: Vec2, direction: Dir, string: &str) -> bool {
+    let mut ray = matrix.ray(start, direction).map(|(_, c)| *c);
+    string.chars().all(|expected| ray.next() == Some(expected))
+}
+
+pub fn part1(matrix: &Matrix) -> i32 {
+    let mut sum = 0;
+    for pos in matrix.positions() {
+        for direction in Vec2::COMPASS {
+            if is_xmas(matrix, pos, direction, "XMAS") {
+                sum += 1;
+            }
+        }
+    }
+    sum
+}
+
+pub fn is_cross_mas(matrix: &Matrix, pos: Vec2) -> bool {
+    if matrix.get(pos) != Some(&'A') {
+        return false;
+    }
+
+    let mut sum = 0;
+    for direction in Vec2::DIAGONAL {
+        match (matrix.get(pos + direction), matrix.get(pos - direction)) {
+            (Some(m), Some(s)) => {
+                if *m == 'M' && *s == 'S' {
+                    sum += 1;
+                }
+            }
+            (_, _) => continue,
+        }
+    }
+    if sum >= 2 {
+        // println!("{}", pos);
+        return true;
+    }
+    false
+}
+
+pub fn part2(matrix: &Matrix) -> i32 {
+    let mut sum = 0;
+    for pos in matrix.positions() {
+        if is_cross_mas(matrix, pos) {
+            sum += 1
+        }
+    }
+    sum
+}
@@ -0,0 +1,32 @@
+//! Random page ordering rules and updates.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+/// Rules for every pair of `size` (at least 3) two digit pages, which are consistent with one
+/// hidden order, and `size` updates (at least one, the parser needs both sections) with an
+/// odd number of pages, some of them in that order.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(size.clamp(3, order.len()));
+
+    let mut rules = Vec::new();
+    for (i, before) in order.iter().enumerate() {
+        for after in &order[i + 1..] {
+            rules.push(format!("{before}|{after}\n"));
+        }
+    }
+    rules.shuffle(rng);
+
+    let updates = (0..size.max(1)).map(|_| {
+        let length = rng.gen_range(0..=(order.len() - 1) / 2) * 2 + 1;
+        let mut update: Vec<usize> = rand::seq::index::sample(rng, order.len(), length).into_vec();
+        if rng.gen_bool(0.5) {
+            update.sort();
+        }
+        let pages: Vec<String> = update.iter().map(|&i| order[i].to_string()).collect();
+        pages.join(",") + "\n"
+    });
+
+    rules.concat() + "\n" + &updates.collect::<String>()
+}
@@ -0,0 +1,155 @@
+pub mod generate;
+
+use std::collections::{HashMap, HashSet};
+
+use common::{Answer, ParseError, Solution};
+use rand::rngs::StdRng;
+
+pub type Page = i32;
+
+#[derive(Debug)]
+pub struct Problem {
+    pub ordering_rules: Vec<(Page, Page)>,
+    pub pages: Vec<Vec<Page>>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Problem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Problem, ParseError> {
+    let [rules, updates] = parsing::sections(input)?;
+    let ordering_rules = rules
+        .lines()
+        .map(|l| parsing::pair(input, l, "|"))
+        .collect::<Result<Vec<(Page, Page)>, ParseError>>()?;
+
+    let pages = updates
+        .lines()
+        .map(|l| parsing::separated(input, l, ','))
+        .collect::<Result<Vec<Vec<Page>>, ParseError>>()?;
+
+    Ok(Problem {
+        ordering_rules,
+        pages,
+    })
+}
+
+pub fn is_correct_order(
+    pages: &Vec<Page>,
+    cannot_occur_after: &HashMap<Page, HashSet<Page>>,
+) -> bool {
+    let mut seen: HashSet<Page> = HashSet::new();
+    for p in pages {
+        if let Some(forbidden) = cannot_occur_after.get(p) {
+            if forbidden.intersection(&seen).next().is_some() {
+                return false;
+            }
+        }
+        seen.insert(*p);
+    }
+    true
+}
+
+pub fn solve_part1(problem_set: &Problem) -> i32 {
+    let cannot_occur_after = problem_set.ordering_rules.iter().fold(
+        HashMap::new(),
+        |mut acc: HashMap<Page, HashSet<Page>>, next| {
+            acc.entry(next.0)
+                .and_modify(|v| {
+                    v.insert(next.1);
+                })
+                .or_insert({
+                    let mut v = HashSet::new();
+                    v.insert(next.1);
+                    v
+                });
+            acc
+        },
+    );
+
+    problem_set
+        .pages
+        .iter()
+        .filter(|pages| is_correct_order(pages, &cannot_occur_after))
+        // .inspect(|pages| println!("{:?}", pages))
+        .map(|pages| {
+            let len = pages.len();
+            if len % 2 == 0 || len == 0 {
+                log::warn!("zero or even number of items: {:?}", pages);
+            }
+            pages[len / 2]
+        })
+        .sum()
+}
+
+pub fn solve_part2(problem_set: &Problem) -> i32 {
+    let cannot_occur_after = problem_set.ordering_rules.iter().fold(
+        HashMap::new(),
+        |mut acc: HashMap<Page, HashSet<Page>>, next| {
+            acc.entry(next.0)
+                .and_modify(|v| {
+                    v.insert(next.1);
+                })
+                .or_insert({
+                    let mut v = HashSet::new();
+                    v.insert(next.1);
+                    v
+                });
+            acc
+        },
+    );
+
+    problem_set
+        .pages
+        .iter()
+        .filter(|pages| !is_correct_order(pages, &cannot_occur_after))
+        .map(|pages| {
+            let mut pages = pages.clone();
+            reorder_pages(&mut pages, &cannot_occur_after);
+            pages
+        })
+        // .inspect(|pages| println!("{:?}", pages))
+        .map(|pages| {
+            let len = pages.len();
+            if len % 2 == 0 || len == 0 {
+                log::warn!("zero or even number of items: {:?}", pages);
+            }
+            pages[len / 2]
+        })
+        .sum()
+}
+
+pub fn reorder_pages(pages: &mut [Page], cannot_occur_after: &HashMap<Page, HashSet<Page>>) {
+    for _ in 0..pages.len() {
+        for (left, right) in (0..pages.len()).zip(1..pages.len()) {
+            match cannot_occur_after.get(&pages[left]) {
+                Some(forbidden) if forbidden.contains(&pages[right]) => {
+                    pages.swap(left, right);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pages.reverse();
+}
@@ -0,0 +1,28 @@
+//! Random guard maps.
+
+use rand::{rngs::StdRng, Rng};
+
+use crate::{forms_loop, parse_input};
+
+/// A `size` by `size` map with a guard that walks off it rather than in a loop.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    loop {
+        let mut map = vec![vec!['.'; size]; size];
+        for cell in map.iter_mut().flatten() {
+            if rng.gen_bool(0.15) {
+                *cell = '#';
+            }
+        }
+        map[rng.gen_range(0..size)][rng.gen_range(0..size)] = '^';
+        let text: String = map
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect();
+
+        let (tile_map, start) = parse_input(&text).unwrap();
+        if !forms_loop(tile_map, &start) {
+            return text;
+        }
+    }
+}
@@ -0,0 +1,181 @@
+pub mod generate;
+
+use std::{collections::HashSet, fmt};
+
+use common::{budget, Answer, ParseError, Part, Solution};
+use grid::{Dir, Grid, Vec2};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+
+#[derive(Debug, Clone)]
+pub enum Tile {
+    Empty,
+    /// Visited, facing each of these directions.
+    Explored(HashSet<Dir>),
+    Obstacle,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Empty => '.',
+            Tile::Explored(_) => '^',
+            Tile::Obstacle => '#',
+        };
+        write!(f, "{c}")
+    }
+}
+
+pub type TileMap = Grid<Tile>;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (TileMap, Vec2);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((tile_map, starting_location): &Self::Input) -> Answer {
+        part1(tile_map, starting_location).into()
+    }
+
+    fn part2((tile_map, starting_location): &Self::Input) -> Answer {
+        part2(tile_map, starting_location).into()
+    }
+
+    fn reference(part: Part) -> Option<fn(&Self::Input) -> Answer> {
+        match part {
+            Part::One => None,
+            Part::Two => Some(|(tile_map, starting_location)| {
+                part2_reference(tile_map, starting_location).into()
+            }),
+        }
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(TileMap, Vec2), ParseError> {
+    let tile_map = Grid::parse(input, |character| match character {
+        '.' => Ok(Tile::Empty),
+        '#' => Ok(Tile::Obstacle),
+        '^' => Ok(Tile::Explored(HashSet::from([Vec2::UP]))),
+        unknown => Err(format!("unrecognized character \'{}\'", unknown)),
+    })?;
+
+    let Some(starting_pos) = tile_map.position(|tile| matches!(tile, Tile::Explored(_))) else {
+        return Err(ParseError::new("did not find starting position"));
+    };
+
+    Ok((tile_map, starting_pos))
+}
+
+pub fn part1(tile_map: &TileMap, starting_location: &Vec2) -> i32 {
+    let explored = explore(tile_map, starting_location);
+    log::debug!("explored map:\n{explored}");
+    explored
+        .iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Explored(_)))
+        .count() as i32
+}
+
+/// The map after the guard walked off it or started walking in a loop, with every tile they
+/// crossed explored.
+pub fn explore(tile_map: &TileMap, starting_location: &Vec2) -> TileMap {
+    walk(tile_map.clone(), starting_location).0
+}
+
+/// Whether the guard ends up walking in a loop. `tile_map` is used to mark where they have
+/// been and in which direction.
+pub fn forms_loop(tile_map: TileMap, starting_location: &Vec2) -> bool {
+    walk(tile_map, starting_location).1
+}
+
+/// Walks the guard until they leave `tile_map` or walk in a loop, marking where they have
+/// been and in which direction. Returns the marked map and whether they looped.
+fn walk(mut tile_map: TileMap, starting_location: &Vec2) -> (TileMap, bool) {
+    let mut location = *starting_location;
+    let mut forward = Vec2::UP;
+    // A guard boxed in by obstacles turns in place forever.
+    let mut turns = 0;
+
+    loop {
+        budget::step();
+        let next_location = location + forward;
+        let tile = tile_map.get_mut(next_location);
+        match tile {
+            None => {
+                return (tile_map, false);
+            }
+            Some(t) => match t {
+                Tile::Empty => {
+                    *t = Tile::Explored({
+                        let mut hash_set = HashSet::new();
+                        hash_set.insert(forward);
+                        hash_set
+                    });
+                    location = next_location;
+                }
+                Tile::Explored(hash_set) => {
+                    if hash_set.contains(&forward) {
+                        return (tile_map, true);
+                    }
+                    hash_set.insert(forward);
+                    location = next_location;
+                }
+                Tile::Obstacle => {
+                    turns += 1;
+                    if turns == 4 {
+                        return (tile_map, true);
+                    }
+                    forward = forward.rotate_right();
+                    continue;
+                }
+            },
+        }
+        turns = 0;
+    }
+}
+
+/// An obstacle off the guard's route does not change it, so only the explored tiles are tried,
+/// unless the guard walks in a loop already.
+pub fn part2(tile_map: &TileMap, starting_location: &Vec2) -> usize {
+    let (explored, looped) = walk(tile_map.clone(), starting_location);
+    if looped {
+        return part2_reference(tile_map, starting_location);
+    }
+    explored
+        .iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Explored(_)))
+        .map(|(pos, _)| pos)
+        .filter(|pos| {
+            let mut tile_map = tile_map.clone();
+            tile_map[*pos] = Tile::Obstacle;
+            forms_loop(tile_map, starting_location)
+        })
+        .inspect(|pos| {
+            log::debug!("obstacle at {pos} forms a loop");
+        })
+        .count()
+}
+
+/// Tries an obstacle on every tile of the map.
+pub fn part2_reference(tile_map: &TileMap, starting_location: &Vec2) -> usize {
+    (0..tile_map.height() as i64)
+        .inspect(|r| {
+            log::info!("row {}...", r + 1);
+        })
+        .cartesian_product(0..tile_map.width() as i64)
+        .map(|(r, c)| Vec2::new(c, r))
+        .filter(|pos| {
+            let mut tile_map = tile_map.clone();
+            tile_map[*pos] = Tile::Obstacle;
+            forms_loop(tile_map, starting_location)
+        })
+        .count()
+}
@@ -0,0 +1,20 @@
+//! Obstacles that leave the guard no way to go.
+
+use common::{Answer, Solution};
+use day6::Day6;
+
+#[test]
+fn a_boxed_in_guard_counts_as_a_loop() {
+    // With an obstacle below the guard, they turn in place forever.
+    let map = Day6::parse(".#.\n#^#\n...\n").unwrap();
+    assert_eq!(Day6::part1(&map), Answer::from(2));
+    assert_eq!(Day6::part2(&map), Answer::from(1));
+}
+
+#[test]
+fn a_guard_boxed_in_from_the_start_stops() {
+    let map = Day6::parse(".#.\n#^#\n.#.\n").unwrap();
+    assert_eq!(Day6::part1(&map), Answer::from(1));
+    // Wherever the new obstacle goes, the guard stays boxed in.
+    assert_eq!(Day6::part2(&map), Answer::from(9));
+}
@@ -0,0 +1,28 @@
+//! Random calibration equations.
+
+use rand::{rngs::StdRng, Rng};
+
+/// `size` equations of 2 to 6 numbers below 100. Most test values are what some choice of
+/// `+`, `*` and `||` gives, the rest are off by a little and likely impossible.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let numbers: Vec<i128> = (0..rng.gen_range(2..=6))
+                .map(|_| rng.gen_range(1..100))
+                .collect();
+            let mut value = numbers[0];
+            for &n in &numbers[1..] {
+                value = match rng.gen_range(0..3) {
+                    0 => value + n,
+                    1 => value * n,
+                    _ => format!("{value}{n}").parse().unwrap(),
+                };
+            }
+            if rng.gen_bool(0.3) {
+                value += rng.gen_range(1..10);
+            }
+            let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+            format!("{value}: {}\n", numbers.join(" "))
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+pub mod generate;
+
+use common::{budget, Answer, ParseError, Solution};
+use parsing::Pattern;
+use rand::rngs::StdRng;
+
+pub type Calibration = (i128, Vec<i128>);
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    let pattern = Pattern::new(r"^(\d+): (.+)$", "a calibration like 190: 10 19");
+    input
+        .lines()
+        .map(|l| {
+            let captures = pattern.captures(input, l)?;
+            let test_value = captures.parse(1)?;
+            let equation = captures.words(2)?;
+            if equation.is_empty() {
+                return Err(ParseError::at(
+                    input,
+                    captures.str(2)?,
+                    "expected at least one number",
+                ));
+            }
+            Ok((test_value, equation))
+        })
+        .collect()
+}
+
+pub fn can_sum_to(target: i128, current_value: i128, items: &[i128]) -> bool {
+    budget::step();
+    if current_value > target {
+        return false;
+    }
+    if items.is_empty() {
+        return target == current_value;
+    }
+    can_sum_to(target, current_value + items[0], &items[1..])
+        || can_sum_to(target, current_value * items[0], &items[1..])
+}
+
+pub fn solve_part1(calibrations: &[Calibration]) -> i128 {
+    calibrations
+        .iter()
+        .filter(|c| can_sum_to(c.0, c.1[0], &c.1[1..]))
+        .inspect(|c| log::debug!("{:?}", c))
+        .map(|c| c.0)
+        .sum()
+}
+
+pub fn concatenate(a: i128, b: i128) -> i128 {
+    let mut result = a.to_string();
+    result.push_str(b.to_string().as_str());
+    result.parse::<i128>().unwrap()
+}
+
+pub fn can_sum_to_with_concat(target: i128, current_value: i128, items: &[i128]) -> bool {
+    budget::step();
+    if current_value > target {
+        return false;
+    }
+    if items.is_empty() {
+        return target == current_value;
+    }
+    can_sum_to_with_concat(target, current_value + items[0], &items[1..])
+        || can_sum_to_with_concat(target, current_value * items[0], &items[1..])
+        || can_sum_to_with_concat(target, concatenate(current_value, items[0]), &items[1..])
+}
+
+pub fn solve_part2(calibrations: &[Calibration]) -> i128 {
+    calibrations
+        .iter()
+        .filter(|c| can_sum_to_with_concat(c.0, c.1[0], &c.1[1..]))
+        .inspect(|c| log::debug!("{:?}", c))
+        .map(|c| c.0)
+        .sum()
+}
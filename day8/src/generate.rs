@@ -0,0 +1,22 @@
+//! Random antenna maps.
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size` by `size` map with 1 to 3 frequencies of 2 to 4 antennas each.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = vec![vec!['.'; size]; size];
+    let mut cells = rand::seq::index::sample(rng, size * size, size * size).into_iter();
+    for _ in 0..rng.gen_range(1..=3) {
+        let frequency = *FREQUENCIES.choose(rng).unwrap() as char;
+        for cell in cells.by_ref().take(rng.gen_range(2..=4)) {
+            map[cell / size][cell % size] = frequency;
+        }
+    }
+
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
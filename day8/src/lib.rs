@@ -0,0 +1,172 @@
+pub mod generate;
+
+use std::collections::HashMap;
+
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Vec2};
+use rand::rngs::StdRng;
+
+pub type Matrix = Grid<char>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Matrix;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Matrix, ParseError> {
+    parsing::char_grid(input)
+}
+
+#[allow(dead_code)]
+fn print(matrix: &Matrix) {
+    print!("{matrix}");
+}
+
+pub fn get_antennas(matrix: &Matrix) -> HashMap<char, Vec<Vec2>> {
+    matrix.iter().filter(|(_, v)| **v != '.').fold(
+        HashMap::new(),
+        |mut acc: HashMap<char, Vec<Vec2>>, (pos, v)| {
+            acc.entry(*v)
+                .and_modify(|e| e.push(pos))
+                .or_insert(vec![pos]);
+            acc
+        },
+    )
+}
+
+fn distance(p1: Vec2, p2: Vec2) -> f32 {
+    let dx = (p2.x - p1.x) as f32;
+    let dy = (p1.y - p2.y) as f32;
+    (dx.powi(2) + dy.powi(2)).sqrt()
+}
+
+fn floats_approx_eq(f1: f32, f2: f32) -> bool {
+    (f1 - f2).abs() < 1e-6
+    // -6: 594
+    // -5: 865
+    // -4: 949
+    // -3: 949
+    // -2: 957
+}
+
+fn direction_vec(a: Vec2, b: Vec2) -> (f32, f32) {
+    let d = distance(a, b);
+    (((b.x - a.x) as f32) / d, ((b.y - a.y) as f32) / d)
+}
+
+fn same_directions(p: Vec2, p1: Vec2, p2: Vec2) -> bool {
+    let d1 = direction_vec(p, p1);
+    let d2 = direction_vec(p, p2);
+    floats_approx_eq(d1.0, d2.0) && floats_approx_eq(d1.1, d2.1)
+}
+
+pub fn solve_part1(matrix: &Matrix) -> i32 {
+    let antennas = get_antennas(matrix);
+    // println!("{:?}", antennas);
+
+    let mut num_antinode_spots: i32 = 0;
+    for row in 0..matrix.height() {
+        for col in 0..matrix.width() {
+            'is_antinode: for wavelength in &antennas {
+                if wavelength.1.len() <= 1 {
+                    continue;
+                }
+                for a1 in 0..wavelength.1.len() {
+                    for a2 in (a1 + 1)..wavelength.1.len() {
+                        let a1 = wavelength.1[a1];
+                        let a2 = wavelength.1[a2];
+                        let p = Vec2::new(col as i64, row as i64);
+                        if !same_directions(p, a1, a2) {
+                            continue;
+                        }
+                        let d1 = distance(p, a1);
+                        let d2 = distance(p, a2);
+                        let ratio = d1 / d2;
+                        if floats_approx_eq(ratio, 2_f32) || floats_approx_eq(ratio, 0.5_f32) {
+                            num_antinode_spots += 1;
+                            // println!("({row},{col}) antinode from {} between {a1} and {a2}", wavelength.0);
+                            break 'is_antinode;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    num_antinode_spots
+}
+
+fn is_multiple(big: f32, small: f32) -> bool {
+    let mut big = big;
+    while big > 0_f32 {
+        if floats_approx_eq(big, small) {
+            return true;
+        }
+        big -= small;
+    }
+    false
+}
+
+pub fn solve_part2(matrix: &Matrix) -> i32 {
+    let antennas = get_antennas(matrix);
+    // println!("{:?}", antennas);
+
+    let mut num_antinode_spots: i32 = 0;
+    for (row, line) in matrix.rows().enumerate() {
+        for (col, cell) in line.iter().enumerate() {
+            if *cell != '.' {
+                num_antinode_spots += 1;
+                log::debug!("({row},{col}) antinode from antenna");
+                continue;
+            }
+            'is_antinode: for wavelength in &antennas {
+                if wavelength.1.len() <= 1 {
+                    continue;
+                }
+                for a1 in 0..wavelength.1.len() {
+                    for a2 in (a1 + 1)..wavelength.1.len() {
+                        let a1 = wavelength.1[a1];
+                        let a2 = wavelength.1[a2];
+                        let p = Vec2::new(col as i64, row as i64);
+                        if !same_directions(p, a1, a2) {
+                            continue;
+                        }
+                        let d1 = distance(p, a1);
+                        let d2 = distance(p, a2);
+
+                        let delta = (d1 - d2).abs();
+
+                        if is_multiple(d1, delta) || is_multiple(d2, delta) {
+                            num_antinode_spots += 1;
+                            log::debug!(
+                                "({row},{col}) antinode from {} between {a1} and {a2}",
+                                wavelength.0
+                            );
+                            break 'is_antinode;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    num_antinode_spots
+}
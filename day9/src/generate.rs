@@ -0,0 +1,15 @@
+//! Random disk maps.
+
+use rand::{rngs::StdRng, Rng};
+
+/// A disk map of `size` files of 1 to 9 blocks, with gaps of 0 to 9 blocks between them.
+pub fn generate(rng: &mut StdRng, size: usize) -> String {
+    let mut disk_map = String::new();
+    for i in 0..size.max(1) {
+        if i > 0 {
+            disk_map.push_str(&rng.gen_range(0..=9).to_string());
+        }
+        disk_map.push_str(&rng.gen_range(1..=9).to_string());
+    }
+    disk_map + "\n"
+}
@@ -0,0 +1,248 @@
+pub mod generate;
+
+use std::iter::repeat_n;
+
+use common::{budget, Answer, ParseError, Solution};
+use rand::rngs::StdRng;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = DiskMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+
+    fn generate(rng: &mut StdRng, size: usize) -> Option<String> {
+        Some(generate::generate(rng, size))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DiskBlock {
+    File { size: usize, id: usize },
+    Empty { gap: usize },
+}
+
+pub type DiskMap = Vec<DiskBlock>;
+
+pub fn parse_input(input: &str) -> Result<DiskMap, ParseError> {
+    Ok(parsing::digits::<u8>(input, input.trim_end())?
+        .into_iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let size = size as usize;
+            if i % 2 == 0 {
+                DiskBlock::File { size, id: i / 2 }
+            } else {
+                DiskBlock::Empty { gap: size }
+            }
+        })
+        .collect())
+}
+
+/// The blocks as they would be laid out on disk, each file as the last digit of its ID,
+/// for debug logs.
+fn show_diskmap(diskmap: &DiskMap) -> String {
+    diskmap
+        .iter()
+        .map(|b| match b {
+            DiskBlock::File { size, id } => repeat_n(
+                char::from_digit((*id % 10).try_into().unwrap(), 10).unwrap(),
+                *size,
+            )
+            .collect::<String>(),
+            DiskBlock::Empty { gap } => repeat_n('.', *gap).collect::<String>(),
+        })
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+pub fn diskmap_checksum(diskmap: &DiskMap) -> usize {
+    diskmap
+        .iter()
+        .map(|b| match b {
+            DiskBlock::File { size, id } => (*size, *id),
+            DiskBlock::Empty { gap } => (*gap, 0),
+        })
+        .flat_map(|(size, id)| repeat_n(id, size))
+        .enumerate()
+        // .inspect(|v| println!("\t{v:?}"))
+        .fold(0, |acc, (seq, id)| acc + seq * id)
+}
+
+pub fn solve_part1(diskmap: &DiskMap) -> usize {
+    let mut diskmap = diskmap.clone();
+    log::debug!("{}", show_diskmap(&diskmap));
+
+    let mut leftmost_empty = 0;
+    let mut rightmost_occupied = diskmap.len() - 1;
+    diskmap.push(DiskBlock::Empty { gap: 0 });
+
+    while leftmost_empty < rightmost_occupied {
+        budget::step();
+        let size_gap_to_fill = match diskmap[leftmost_empty] {
+            DiskBlock::File { size: _, id: _ } => {
+                leftmost_empty += 1;
+                continue;
+            }
+            DiskBlock::Empty { gap } => gap,
+        };
+        let (num_blocks_to_move, id) = match diskmap[rightmost_occupied] {
+            DiskBlock::File { size, id } => (size, id),
+            DiskBlock::Empty { gap: _ } => {
+                rightmost_occupied -= 1;
+                continue;
+            }
+        };
+
+        if size_gap_to_fill <= num_blocks_to_move {
+            diskmap[leftmost_empty] = DiskBlock::File {
+                size: size_gap_to_fill,
+                id,
+            };
+            if size_gap_to_fill == num_blocks_to_move {
+                diskmap.remove(rightmost_occupied);
+            } else {
+                diskmap[rightmost_occupied] = DiskBlock::File {
+                    size: num_blocks_to_move - size_gap_to_fill,
+                    id,
+                };
+            }
+        } else {
+            diskmap.insert(
+                leftmost_empty,
+                DiskBlock::File {
+                    size: num_blocks_to_move,
+                    id,
+                },
+            );
+            diskmap[leftmost_empty + 1] = DiskBlock::Empty {
+                gap: size_gap_to_fill - num_blocks_to_move,
+            };
+            diskmap.remove(rightmost_occupied + 1);
+        }
+
+        log::trace!("{}", show_diskmap(&diskmap));
+    }
+
+    diskmap_checksum(&diskmap)
+}
+
+fn next_gap_with_size(diskmap: &DiskMap, min_size: usize) -> Option<(usize, usize)> {
+    for (i, block) in diskmap.iter().enumerate() {
+        match *block {
+            DiskBlock::File { size: _, id: _ } => {
+                continue;
+            }
+            DiskBlock::Empty { gap } => {
+                if gap >= min_size {
+                    return Option::Some((i, gap));
+                }
+                continue;
+            }
+        }
+    }
+    None
+}
+
+#[allow(dead_code)]
+fn merge_empty_blocks(diskmap: &mut DiskMap) -> usize {
+    let mut num_merges = 0;
+    let mut l = 0;
+    while l < diskmap.len() - 1 {
+        if let DiskBlock::Empty { gap } = diskmap[l] {
+            if gap == 0 {
+                diskmap.remove(l);
+                num_merges += 1;
+                l -= 1;
+            }
+        }
+
+        let r = l + 1;
+        match (diskmap[l], diskmap[r]) {
+            (DiskBlock::Empty { gap: l_gap }, DiskBlock::Empty { gap: r_gap }) => {
+                diskmap.remove(r);
+                diskmap[l] = DiskBlock::Empty { gap: l_gap + r_gap };
+                num_merges += 1;
+                l -= 1;
+            }
+            _ => {
+                l += 1;
+                continue;
+            }
+        }
+        l += 1;
+    }
+    num_merges
+}
+
+pub fn solve_part2(diskmap: &DiskMap) -> usize {
+    let mut diskmap = diskmap.clone();
+    log::debug!("{}", show_diskmap(&diskmap));
+
+    let mut file_to_move: isize = diskmap.len() as isize - 1;
+    while file_to_move >= 0 {
+        budget::step();
+        let (file_size, id) = match diskmap[file_to_move as usize] {
+            DiskBlock::File { size, id } => (size, id),
+            DiskBlock::Empty { gap: _ } => {
+                file_to_move -= 1;
+                continue;
+            }
+        };
+
+        let (empty_space_to_fill, gap_size) = match next_gap_with_size(&diskmap, file_size) {
+            Some(v) => v,
+            None => {
+                file_to_move -= 1;
+                continue;
+            }
+        };
+
+        if empty_space_to_fill >= file_to_move as usize {
+            file_to_move -= 1;
+            continue;
+        }
+
+        if file_size == empty_space_to_fill {
+            diskmap[empty_space_to_fill] = DiskBlock::File {
+                size: file_size,
+                id,
+            };
+        } else {
+            diskmap[empty_space_to_fill] = DiskBlock::File {
+                size: file_size,
+                id,
+            };
+            diskmap.insert(
+                empty_space_to_fill + 1,
+                DiskBlock::Empty {
+                    gap: gap_size - file_size,
+                },
+            );
+            file_to_move += 1;
+        }
+        diskmap.remove(file_to_move as usize);
+        diskmap.insert(file_to_move as usize, DiskBlock::Empty { gap: file_size });
+        file_to_move -= 1;
+
+        log::trace!("{}", show_diskmap(&diskmap));
+
+        // let num_merges = merge_empty_blocks(&mut diskmap);
+        // file_to_move -= num_merges as isize;
+        // log::trace!("{}", show_diskmap(&diskmap));
+    }
+
+    diskmap_checksum(&diskmap)
+}
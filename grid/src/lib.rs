@@ -0,0 +1,227 @@
+//! A rectangular 2D grid, as used by most of the map-shaped puzzles.
+//!
+//! Positions are [`Vec2`]s with signed components so that stepping off the edge of the grid
+//! is just a failed lookup rather than an underflow.
+
+mod vec2;
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+pub use vec2::{gcd, Dir, Vec2};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row-major.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a character map, one row per line, converting each character with `cell`.
+    ///
+    /// When `cell` returns an error message the error points at the offending character. The
+    /// input must not be empty and every line must be as long as the first one, apart from
+    /// empty lines at the end, which are ignored.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut lines: Vec<&str> = input.lines().collect();
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+        let Some(first) = lines.first() else {
+            return Err(ParseError::new("input is empty"));
+        };
+        let width = first.chars().count();
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in lines {
+            if line.chars().count() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected {width} columns"),
+                ));
+            }
+            for (i, c) in line.char_indices() {
+                let value = cell(c).map_err(|message| {
+                    ParseError::at(input, &line[i..i + c.len_utf8()], message)
+                })?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        (0..self.width as i64).contains(&pos.x) && (0..self.height as i64).contains(&pos.y)
+    }
+
+    fn offset(&self, pos: Vec2) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// The cell at `pos`, `None` if it is outside the grid.
+    pub fn get(&self, pos: Vec2) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2> {
+        let width = self.width as i64;
+        (0..self.height as i64).flat_map(move |y| (0..width).map(move |x| Vec2::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The rows as slices, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {col} is outside a grid {} wide",
+            self.width
+        );
+        self.cells[col..].iter().step_by(self.width.max(1))
+    }
+
+    /// The cells from `start` going in a straight line by `step` until the edge of the grid,
+    /// `start` included. With a diagonal step this walks a diagonal.
+    pub fn ray(&self, start: Vec2, step: Dir) -> impl Iterator<Item = (Vec2, &T)> {
+        std::iter::successors(Some(start), move |pos| Some(*pos + step))
+            .map_while(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// The up to four positions next to `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.neighbours(pos, &Vec2::CARDINAL)
+    }
+
+    /// The up to eight positions around `pos`, diagonals included, that are inside the grid.
+    pub fn neighbours8(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.neighbours(pos, &Vec2::COMPASS)
+    }
+
+    fn neighbours<'a>(&'a self, pos: Vec2, steps: &'a [Dir]) -> impl Iterator<Item = Vec2> + 'a {
+        steps
+            .iter()
+            .map(move |step| pos + *step)
+            .filter(|next| self.contains(*next))
+    }
+
+    /// The position of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Vec2> {
+        self.iter().find(|(_, v)| predicate(v)).map(|(pos, _)| pos)
+    }
+
+    /// The positions of all cells equal to `value`.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Vec2> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// The position of the first cell equal to `value`.
+    pub fn find(&self, value: &T) -> Option<Vec2>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+}
+
+impl Grid<char> {
+    /// Parses a character map as is, see [`Grid::parse`].
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, Ok)
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2) -> &T {
+        match self.get(pos) {
+            Some(value) => value,
+            None => panic!(
+                "{pos} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(value) => value,
+            None => panic!("{pos} is outside of the {width}x{height} grid"),
+        }
+    }
+}
+
+/// Renders the grid as it was parsed, one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
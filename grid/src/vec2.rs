@@ -0,0 +1,148 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A point or a direction on a grid: `x` grows to the right and `y` grows downwards, so
+/// `y` is the row and `x` the column of a character map.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A [`Vec2`] used as a step, like [`Vec2::UP`].
+pub type Dir = Vec2;
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const UP: Dir = Vec2::new(0, -1);
+    pub const DOWN: Dir = Vec2::new(0, 1);
+    pub const LEFT: Dir = Vec2::new(-1, 0);
+    pub const RIGHT: Dir = Vec2::new(1, 0);
+
+    pub const UP_RIGHT: Dir = Vec2::new(1, -1);
+    pub const DOWN_RIGHT: Dir = Vec2::new(1, 1);
+    pub const DOWN_LEFT: Dir = Vec2::new(-1, 1);
+    pub const UP_LEFT: Dir = Vec2::new(-1, -1);
+
+    /// The four orthogonal directions, clockwise starting from up.
+    pub const CARDINAL: [Dir; 4] = [Vec2::UP, Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT];
+
+    /// The four diagonal directions, clockwise starting from up-right.
+    pub const DIAGONAL: [Dir; 4] = [
+        Vec2::UP_RIGHT,
+        Vec2::DOWN_RIGHT,
+        Vec2::DOWN_LEFT,
+        Vec2::UP_LEFT,
+    ];
+
+    /// All eight directions, clockwise starting from up.
+    pub const COMPASS: [Dir; 8] = [
+        Vec2::UP,
+        Vec2::UP_RIGHT,
+        Vec2::RIGHT,
+        Vec2::DOWN_RIGHT,
+        Vec2::DOWN,
+        Vec2::DOWN_LEFT,
+        Vec2::LEFT,
+        Vec2::UP_LEFT,
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Turned 90° clockwise, as seen on the screen: up becomes right.
+    pub const fn rotate_right(self) -> Self {
+        Vec2::new(-self.y, self.x)
+    }
+
+    /// Turned 90° counter-clockwise: up becomes left.
+    pub const fn rotate_left(self) -> Self {
+        Vec2::new(self.y, -self.x)
+    }
+
+    pub const fn opposite(self) -> Self {
+        Vec2::new(-self.x, -self.y)
+    }
+
+    /// The length of the vector in grid steps, without diagonals.
+    pub const fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub const fn manhattan_distance(self, other: Vec2) -> i64 {
+        Vec2::new(self.x - other.x, self.y - other.y).manhattan()
+    }
+
+    /// The smallest vector with the same direction whose components are integers, e.g.
+    /// `(4, -6)` becomes `(2, -3)`. Zero stays zero.
+    pub fn reduced(self) -> Self {
+        let divisor = gcd(self.x, self.y);
+        if divisor == 0 {
+            return self;
+        }
+        Vec2::new(self.x / divisor, self.y / divisor)
+    }
+}
+
+/// Greatest common divisor, always non-negative.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        self.opposite()
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
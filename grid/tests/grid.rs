@@ -0,0 +1,97 @@
+//! Parsing, lookups and walks on a grid.
+
+use grid::{Grid, Vec2};
+
+const MAP: &str = "#.#\n..x\n";
+
+#[test]
+fn parses_a_character_map() {
+    let grid = Grid::parse_chars(MAP).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Vec2::new(2, 1)], 'x');
+    assert_eq!(grid.to_string(), MAP);
+
+    // Blank lines at the end are not rows.
+    assert_eq!(Grid::parse_chars("#.#\n..x\n\n\n").unwrap(), grid);
+    assert_eq!(Grid::parse_chars("#.#\r\n..x\r\n\r\n").unwrap(), grid);
+}
+
+#[test]
+fn parse_errors_point_at_the_offending_text() {
+    let error = Grid::parse(MAP, |c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(format!("unexpected {c:?}")),
+    })
+    .unwrap_err();
+    assert_eq!(error.message(), "unexpected 'x'");
+    assert_eq!(error.line(), Some(2));
+
+    let error = Grid::parse_chars("#.#\n..\n").unwrap_err();
+    assert_eq!(error.message(), "expected 3 columns");
+    assert_eq!(error.line(), Some(2));
+
+    assert!(Grid::parse_chars("").is_err());
+    assert!(Grid::parse_chars("\n\n").is_err());
+}
+
+#[test]
+fn lookups_outside_the_grid_fail() {
+    let grid = Grid::parse_chars(MAP).unwrap();
+    assert!(grid.contains(Vec2::new(0, 0)));
+    assert!(grid.contains(Vec2::new(2, 1)));
+    for outside in [
+        Vec2::new(-1, 0),
+        Vec2::new(0, -1),
+        Vec2::new(3, 0),
+        Vec2::new(0, 2),
+    ] {
+        assert!(!grid.contains(outside), "{outside}");
+        assert_eq!(grid.get(outside), None, "{outside}");
+    }
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = Grid::new(3, 3, 0);
+    let corner = Vec2::new(0, 0);
+    assert_eq!(
+        grid.neighbours4(corner).collect::<Vec<_>>(),
+        [Vec2::new(1, 0), Vec2::new(0, 1)]
+    );
+    assert_eq!(grid.neighbours8(corner).count(), 3);
+    let centre = Vec2::new(1, 1);
+    assert_eq!(grid.neighbours4(centre).count(), 4);
+    assert_eq!(grid.neighbours8(centre).count(), 8);
+}
+
+#[test]
+fn walks_rows_columns_and_rays() {
+    let grid = Grid::parse_chars("abc\ndef\nghi\n").unwrap();
+    assert_eq!(grid.row(1).collect::<String>(), "def");
+    assert_eq!(grid.column(2).collect::<String>(), "cfi");
+    assert_eq!(grid.rows().count(), 3);
+
+    let diagonal: String = grid
+        .ray(Vec2::new(0, 0), Vec2::DOWN_RIGHT)
+        .map(|(_, c)| c)
+        .collect();
+    assert_eq!(diagonal, "aei");
+    let up: Vec<Vec2> = grid
+        .ray(Vec2::new(1, 2), Vec2::UP)
+        .map(|(pos, _)| pos)
+        .collect();
+    assert_eq!(up, [Vec2::new(1, 2), Vec2::new(1, 1), Vec2::new(1, 0)]);
+    assert_eq!(grid.ray(Vec2::new(5, 5), Vec2::UP).count(), 0);
+
+    assert_eq!(grid.find(&'f'), Some(Vec2::new(2, 1)));
+    assert_eq!(grid.position(|c| *c > 'g'), Some(Vec2::new(1, 2)));
+}
+
+#[test]
+#[should_panic(expected = "column 3 is outside a grid 3 wide")]
+fn rejects_a_column_past_the_edge() {
+    // Without the check this would start on the next row and give "dg".
+    let grid = Grid::parse_chars("abc\ndef\nghi\n").unwrap();
+    grid.column(3).for_each(drop);
+}
@@ -0,0 +1,38 @@
+//! Points and directions.
+
+use grid::{gcd, Vec2};
+
+#[test]
+fn turning_goes_round_the_compass() {
+    assert_eq!(Vec2::UP.rotate_right(), Vec2::RIGHT);
+    assert_eq!(Vec2::UP.rotate_left(), Vec2::LEFT);
+    assert_eq!(Vec2::UP_RIGHT.rotate_right(), Vec2::DOWN_RIGHT);
+    for (i, dir) in Vec2::CARDINAL.into_iter().enumerate() {
+        assert_eq!(dir.rotate_right(), Vec2::CARDINAL[(i + 1) % 4]);
+        assert_eq!(dir.rotate_left().rotate_right(), dir);
+        assert_eq!(dir.rotate_right().rotate_right(), dir.opposite());
+        assert_eq!(-dir, dir.opposite());
+    }
+}
+
+#[test]
+fn arithmetic_and_distances() {
+    let (a, b) = (Vec2::new(3, -2), Vec2::new(-1, 4));
+    assert_eq!(a + b, Vec2::new(2, 2));
+    assert_eq!(a - b, Vec2::new(4, -6));
+    assert_eq!(a * 3, Vec2::new(9, -6));
+    assert_eq!(a.manhattan(), 5);
+    assert_eq!(a.manhattan_distance(b), 10);
+}
+
+#[test]
+fn reduction_divides_by_the_gcd() {
+    assert_eq!(gcd(12, -18), 6);
+    assert_eq!(gcd(-7, 0), 7);
+    assert_eq!(gcd(0, 0), 0);
+
+    assert_eq!(Vec2::new(4, -6).reduced(), Vec2::new(2, -3));
+    assert_eq!(Vec2::new(0, -5).reduced(), Vec2::UP);
+    assert_eq!(Vec2::new(3, 5).reduced(), Vec2::new(3, 5));
+    assert_eq!(Vec2::ZERO.reduced(), Vec2::ZERO);
+}
@@ -0,0 +1,202 @@
+//! Helpers shared by the days' parsers.
+//!
+//! Every function takes the whole puzzle `input` next to the `text` it works on, which must be
+//! a slice of `input`, so that errors can point at the offending text (see
+//! [`ParseError::at`]).
+
+use std::str::FromStr;
+
+use common::ParseError;
+use grid::Grid;
+use regex::Regex;
+
+/// Splits text into paragraphs, blocks of consecutive non-empty lines. The paragraphs borrow
+/// from the text and do not include the trailing newline.
+pub fn paragraphs(text: &str) -> Paragraphs<'_> {
+    Paragraphs { remaining: text }
+}
+
+pub struct Paragraphs<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> Iterator for Paragraphs<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = None;
+        let mut end = 0;
+        let mut consumed = 0;
+        for line in self.remaining.split_inclusive('\n') {
+            let offset = consumed;
+            consumed += line.len();
+            if line.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+                continue;
+            }
+            start.get_or_insert(offset);
+            end = offset + line.trim_end().len();
+        }
+
+        let paragraph = start.map(|start| &self.remaining[start..end]);
+        self.remaining = &self.remaining[consumed..];
+        paragraph
+    }
+}
+
+/// Exactly `N` paragraphs, like the rules and the updates of day 5.
+pub fn sections<const N: usize>(input: &str) -> Result<[&str; N], ParseError> {
+    let found: Vec<&str> = paragraphs(input).collect();
+    found.try_into().map_err(|found: Vec<&str>| {
+        ParseError::new(format!(
+            "expected {N} sections separated by an empty line, found {}",
+            found.len()
+        ))
+    })
+}
+
+/// Parses `text` as a `T`, like a number.
+pub fn value<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, format!("cannot parse {text}")))
+}
+
+/// Parses every whitespace-separated word of `text`.
+pub fn words<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(|w| value(input, w)).collect()
+}
+
+/// Parses the parts of `text` between `separator`s, ignoring whitespace around them.
+pub fn separated<T: FromStr>(
+    input: &str,
+    text: &str,
+    separator: char,
+) -> Result<Vec<T>, ParseError> {
+    text.split(separator)
+        .map(|s| value(input, s.trim()))
+        .collect()
+}
+
+/// Two values separated by `delimiter`, like `47|53`, ignoring whitespace around them.
+pub fn pair<A: FromStr, B: FromStr>(
+    input: &str,
+    text: &str,
+    delimiter: &str,
+) -> Result<(A, B), ParseError> {
+    let (a, b) = text.split_once(delimiter).ok_or_else(|| {
+        ParseError::at(
+            input,
+            text,
+            format!("expected two values separated by {delimiter:?}"),
+        )
+    })?;
+    Ok((value(input, a.trim())?, value(input, b.trim())?))
+}
+
+/// Every signed integer in `text`, ignoring whatever is between them: `X+94, Y=-34` gives
+/// `[94, -34]`. A `-` right after a digit separates rather than signs: `3-5` gives `[3, 5]`.
+pub fn integers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut end = 0;
+    while let Some(found) = text[end..].find(|c: char| c.is_ascii_digit()) {
+        let digits = end + found;
+        let signed = digits > 0
+            && bytes[digits - 1] == b'-'
+            && !(digits > 1 && bytes[digits - 2].is_ascii_digit());
+        let start = digits - usize::from(signed);
+        end = text[digits..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(text.len(), |len| digits + len);
+        numbers.push(value(input, &text[start..end])?);
+    }
+    Ok(numbers)
+}
+
+/// Each character of `text` as a digit.
+pub fn digits<T: From<u8>>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.char_indices()
+        .map(|(i, c)| {
+            digit(c).ok_or_else(|| {
+                ParseError::at(input, &text[i..i + c.len_utf8()], "expected a digit")
+            })
+        })
+        .collect()
+}
+
+fn digit<T: From<u8>>(c: char) -> Option<T> {
+    c.to_digit(10).map(|d| T::from(d as u8))
+}
+
+/// A map of single digits, like the heights of day 10.
+pub fn digit_grid<T: From<u8>>(input: &str) -> Result<Grid<T>, ParseError> {
+    Grid::parse(input, |c| {
+        digit(c).ok_or_else(|| "expected a digit".to_string())
+    })
+}
+
+/// A map of characters, taken as they are.
+pub fn char_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_chars(input)
+}
+
+/// A regex with a description of what it matches, for the error when it does not.
+#[derive(Debug)]
+pub struct Pattern {
+    regex: Regex,
+    description: &'static str,
+}
+
+/// The groups of a [`Pattern`] match, parsed on demand.
+#[derive(Debug)]
+pub struct Captures<'a> {
+    input: &'a str,
+    text: &'a str,
+    captures: regex::Captures<'a>,
+}
+
+impl Pattern {
+    /// Panics if `regex` is invalid, it is a literal in the code.
+    pub fn new(regex: &str, description: &'static str) -> Pattern {
+        Pattern {
+            regex: Regex::new(regex).unwrap(),
+            description,
+        }
+    }
+
+    /// Matches the pattern somewhere in `text`, an error "expected {description}" if it does
+    /// not match.
+    pub fn captures<'a>(&self, input: &'a str, text: &'a str) -> Result<Captures<'a>, ParseError> {
+        let captures = self
+            .regex
+            .captures(text)
+            .ok_or_else(|| ParseError::at(input, text, format!("expected {}", self.description)))?;
+        Ok(Captures {
+            input,
+            text,
+            captures,
+        })
+    }
+}
+
+impl<'a> Captures<'a> {
+    /// The text of group `i`, an error if the group did not take part in the match.
+    pub fn str(&self, i: usize) -> Result<&'a str, ParseError> {
+        self.captures
+            .get(i)
+            .map(|m| m.as_str())
+            .ok_or_else(|| ParseError::at(self.input, self.text, format!("missing group {i}")))
+    }
+
+    /// Group `i` parsed as a `T`.
+    pub fn parse<T: FromStr>(&self, i: usize) -> Result<T, ParseError> {
+        value(self.input, self.str(i)?)
+    }
+
+    /// Group `i` split on whitespace, each word parsed as a `T`.
+    pub fn words<T: FromStr>(&self, i: usize) -> Result<Vec<T>, ParseError> {
+        words(self.input, self.str(i)?)
+    }
+}
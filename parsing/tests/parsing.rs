@@ -0,0 +1,62 @@
+use parsing::Pattern;
+
+#[test]
+fn splits_paragraphs_on_blank_lines() {
+    let input = "\na\nb\n\n  \nc\n\n";
+    assert_eq!(
+        parsing::paragraphs(input).collect::<Vec<_>>(),
+        ["a\nb", "c"]
+    );
+    assert_eq!(parsing::sections::<2>(input).unwrap(), ["a\nb", "c"]);
+    assert!(parsing::sections::<3>(input).is_err());
+}
+
+#[test]
+fn extracts_signed_integers() {
+    let input = "p=0,-4 v=-3,12 x-y 7";
+    assert_eq!(
+        parsing::integers::<i64>(input, input).unwrap(),
+        [0, -4, -3, 12, 7]
+    );
+    // A minus between two numbers is a range or a difference, not a sign.
+    let input = "3-5, -2--1";
+    assert_eq!(
+        parsing::integers::<i64>(input, input).unwrap(),
+        [3, 5, -2, -1]
+    );
+}
+
+#[test]
+fn points_errors_at_the_offending_text() {
+    let input = "1,2\n3,x\n";
+    let line = input.lines().nth(1).unwrap();
+    let error = parsing::separated::<i32>(input, line, ',').unwrap_err();
+    assert_eq!((error.line(), error.column()), (Some(2), Some(3)));
+    assert_eq!(error.text(), Some("x"));
+
+    let error = parsing::pair::<i32, i32>(input, line, "|").unwrap_err();
+    assert_eq!(error.text(), Some("3,x"));
+}
+
+#[test]
+fn parses_regex_captures() {
+    let pattern = Pattern::new(r"(\d+): (.*)", "a calibration");
+    let input = "190: 10 19\nnope";
+    let captures = pattern.captures(input, &input[..10]).unwrap();
+    assert_eq!(captures.parse::<u32>(1).unwrap(), 190);
+    assert_eq!(captures.words::<u32>(2).unwrap(), [10, 19]);
+    // The captures borrow from the input rather than copying it.
+    assert!(std::ptr::eq(captures.str(2).unwrap(), &input[5..10]));
+
+    let error = pattern.captures(input, &input[11..]).unwrap_err();
+    assert_eq!(error.message(), "expected a calibration");
+    assert_eq!(error.line(), Some(2));
+}
+
+#[test]
+fn parses_digit_grids() {
+    let grid = parsing::digit_grid::<u8>("012\n345\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.find(&4).map(|p| (p.x, p.y)), Some((1, 1)));
+    assert!(parsing::digit_grid::<u8>("01\n2a\n").is_err());
+}